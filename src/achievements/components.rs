@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct AchievementsMenu {}
+#[derive(Component)]
+pub struct BackButton {}
+
+#[derive(Component)]
+pub struct ToastContainer {}
+#[derive(Component)]
+pub struct Toast {
+    pub timer: Timer,
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::AchievementUnlocked,
+    game::{network::is_authority, SimulationState},
+    AppState,
+};
+
+use self::systems::{
+    interactions::interact_with_back_button,
+    layout::{despawn_achievements_menu, spawn_achievements_menu},
+    toasts::{expire_toasts, spawn_toast_container, spawn_toasts},
+    tracking::{evaluate_achievements, reset_run_progress, track_run_progress},
+};
+use resources::{AchievementDefinitions, RunProgress, UnlockedAchievements};
+
+mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+pub const ACHIEVEMENTS_PATH: &str = "config/achievements.ron";
+pub const TOAST_SECONDS: f32 = 3.0;
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AchievementUnlocked>()
+            .insert_resource(AchievementDefinitions::load())
+            .insert_resource(UnlockedAchievements::load())
+            .init_resource::<RunProgress>()
+            .add_startup_system(spawn_toast_container)
+            .add_system(reset_run_progress.in_schedule(OnEnter(AppState::Game)))
+            .add_systems(
+                (track_run_progress, evaluate_achievements)
+                    .chain()
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
+            )
+            .add_systems((spawn_toasts, expire_toasts))
+            .add_system(spawn_achievements_menu.in_schedule(OnEnter(AppState::Achievements)))
+            .add_system(interact_with_back_button.in_set(OnUpdate(AppState::Achievements)))
+            .add_system(despawn_achievements_menu.in_schedule(OnExit(AppState::Achievements)));
+    }
+}
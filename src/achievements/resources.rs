@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ACHIEVEMENTS_PATH;
+use crate::config::load_data_file;
+use crate::persistence;
+
+pub const UNLOCKED_ACHIEVEMENTS_FILE: &str = "achievements.ron";
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AchievementDefinition {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: AchievementCondition,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub enum AchievementCondition {
+    StarsInRun(u32),
+    SurviveSeconds(f32),
+    NearMissesInRun(u32),
+    ScoreInRun(u32),
+    ComboInRun(u32),
+    WavesInRun(u32),
+}
+
+impl AchievementCondition {
+    pub fn is_met(&self, run_progress: &RunProgress, score: u32) -> bool {
+        match *self {
+            AchievementCondition::StarsInRun(stars) => run_progress.stars >= stars,
+            AchievementCondition::SurviveSeconds(seconds) => run_progress.time_alive >= seconds,
+            AchievementCondition::NearMissesInRun(near_misses) => {
+                run_progress.near_misses >= near_misses
+            }
+            AchievementCondition::ScoreInRun(target) => score >= target,
+            AchievementCondition::ComboInRun(combo) => run_progress.best_combo >= combo,
+            AchievementCondition::WavesInRun(waves) => run_progress.waves >= waves,
+        }
+    }
+}
+
+#[derive(Resource, Deserialize, Debug, Default)]
+#[serde(transparent)]
+pub struct AchievementDefinitions {
+    pub definitions: Vec<AchievementDefinition>,
+}
+
+impl AchievementDefinitions {
+    pub fn load() -> AchievementDefinitions {
+        load_data_file(ACHIEVEMENTS_PATH)
+    }
+}
+
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct UnlockedAchievements {
+    pub ids: Vec<String>,
+}
+
+impl UnlockedAchievements {
+    pub fn load() -> UnlockedAchievements {
+        persistence::load_or_default(UNLOCKED_ACHIEVEMENTS_FILE)
+    }
+
+    pub fn save(&self) {
+        persistence::save(UNLOCKED_ACHIEVEMENTS_FILE, self);
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.iter().any(|unlocked| unlocked == id)
+    }
+}
+
+// What the player has done so far this run, reset every time a game starts.
+#[derive(Resource, Default, Debug)]
+pub struct RunProgress {
+    pub stars: u32,
+    pub near_misses: u32,
+    pub time_alive: f32,
+    pub best_combo: u32,
+    pub waves: u32,
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+pub const UNLOCKED_COLOUR: Color = Color::rgb(1.0, 0.85, 0.2);
+pub const LOCKED_COLOUR: Color = Color::rgb(0.5, 0.5, 0.5);
+pub const TOAST_COLOUR: Color = Color::rgba(0.1, 0.1, 0.1, 0.85);
+
+pub const ACHIEVEMENT_ROW_STYLE: Style = Style {
+    flex_direction: FlexDirection::Column,
+    justify_content: JustifyContent::Center,
+    size: Size::new(Val::Px(640.), Val::Auto),
+    ..Style::DEFAULT
+};
+
+pub const TOAST_CONTAINER_STYLE: Style = Style {
+    position_type: PositionType::Absolute,
+    position: UiRect {
+        top: Val::Px(16.),
+        right: Val::Px(16.),
+        left: Val::Auto,
+        bottom: Val::Auto,
+    },
+    flex_direction: FlexDirection::Column,
+    align_items: AlignItems::FlexEnd,
+    gap: Size::new(Val::Px(8.), Val::Px(8.)),
+    ..Style::DEFAULT
+};
+
+pub const TOAST_STYLE: Style = Style {
+    flex_direction: FlexDirection::Column,
+    padding: UiRect::all(Val::Px(12.)),
+    ..Style::DEFAULT
+};
+
+pub fn get_name_text_style(game_assets: &GameAssets, colour: Color) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 28.,
+        color: colour,
+    }
+}
+
+pub fn get_description_text_style(game_assets: &GameAssets, colour: Color) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 20.,
+        color: colour,
+    }
+}
@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use crate::{
+    achievements::{
+        components::{AchievementsMenu, BackButton},
+        resources::{AchievementDefinitions, UnlockedAchievements},
+        styles::{
+            get_description_text_style, get_name_text_style, ACHIEVEMENT_ROW_STYLE, LOCKED_COLOUR,
+            UNLOCKED_COLOUR,
+        },
+    },
+    assets::GameAssets,
+    main_menu::styles::{
+        get_button_text_style, get_title_text_style, BUTTON_STYLE, MAIN_MENU_STYLE,
+        NORMAL_BUTTON_COLOUR,
+    },
+};
+
+pub fn spawn_achievements_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    achievement_definitions: Res<AchievementDefinitions>,
+    unlocked_achievements: Res<UnlockedAchievements>,
+) {
+    build_achievements_menu(
+        &mut commands,
+        &game_assets,
+        &achievement_definitions,
+        &unlocked_achievements,
+    );
+}
+
+pub fn despawn_achievements_menu(
+    mut commands: Commands,
+    achievements_menu_query: Query<Entity, With<AchievementsMenu>>,
+) {
+    if let Ok(achievements_menu_entity) = achievements_menu_query.get_single() {
+        commands
+            .entity(achievements_menu_entity)
+            .despawn_recursive();
+    }
+}
+
+pub fn build_achievements_menu(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    achievement_definitions: &AchievementDefinitions,
+    unlocked_achievements: &UnlockedAchievements,
+) -> Entity {
+    let unlocked_count = achievement_definitions
+        .definitions
+        .iter()
+        .filter(|definition| unlocked_achievements.contains(&definition.id))
+        .count();
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: MAIN_MENU_STYLE,
+                ..default()
+            },
+            AchievementsMenu {},
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn(TextBundle::from_section(
+                "Achievements",
+                get_title_text_style(game_assets),
+            ));
+            parent.spawn(TextBundle::from_section(
+                format!(
+                    "{} / {} unlocked",
+                    unlocked_count,
+                    achievement_definitions.definitions.len()
+                ),
+                get_button_text_style(game_assets),
+            ));
+            // List
+            for definition in achievement_definitions.definitions.iter() {
+                let colour = if unlocked_achievements.contains(&definition.id) {
+                    UNLOCKED_COLOUR
+                } else {
+                    LOCKED_COLOUR
+                };
+                parent
+                    .spawn(NodeBundle {
+                        style: ACHIEVEMENT_ROW_STYLE,
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            definition.name.clone(),
+                            get_name_text_style(game_assets, colour),
+                        ));
+                        parent.spawn(TextBundle::from_section(
+                            definition.description.clone(),
+                            get_description_text_style(game_assets, colour),
+                        ));
+                    });
+            }
+            // Back
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    BackButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Back",
+                        get_button_text_style(game_assets),
+                    ));
+                });
+        })
+        .id()
+}
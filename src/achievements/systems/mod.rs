@@ -0,0 +1,4 @@
+pub mod interactions;
+pub mod layout;
+pub mod toasts;
+pub mod tracking;
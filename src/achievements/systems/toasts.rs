@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use crate::{
+    achievements::{
+        components::{Toast, ToastContainer},
+        styles::{
+            get_description_text_style, get_name_text_style, TOAST_COLOUR, TOAST_CONTAINER_STYLE,
+            TOAST_STYLE, UNLOCKED_COLOUR,
+        },
+        TOAST_SECONDS,
+    },
+    assets::GameAssets,
+    events::AchievementUnlocked,
+};
+
+pub fn spawn_toast_container(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: TOAST_CONTAINER_STYLE,
+            // Above whichever menu happens to be open.
+            z_index: ZIndex::Global(10),
+            ..default()
+        },
+        ToastContainer {},
+    ));
+}
+
+pub fn spawn_toasts(
+    mut commands: Commands,
+    mut achievement_unlocked_event_reader: EventReader<AchievementUnlocked>,
+    toast_container_query: Query<Entity, With<ToastContainer>>,
+    game_assets: Res<GameAssets>,
+) {
+    let Ok(toast_container_entity) = toast_container_query.get_single() else {
+        return;
+    };
+
+    for event in achievement_unlocked_event_reader.iter() {
+        commands
+            .entity(toast_container_entity)
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: TOAST_STYLE,
+                            background_color: TOAST_COLOUR.into(),
+                            ..default()
+                        },
+                        Toast {
+                            timer: Timer::from_seconds(TOAST_SECONDS, TimerMode::Once),
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            format!("Achievement unlocked: {}", event.name),
+                            get_name_text_style(&game_assets, UNLOCKED_COLOUR),
+                        ));
+                        parent.spawn(TextBundle::from_section(
+                            event.description.clone(),
+                            get_description_text_style(&game_assets, Color::WHITE),
+                        ));
+                    });
+            });
+    }
+}
+
+pub fn expire_toasts(
+    mut commands: Commands,
+    mut toast_query: Query<(Entity, &mut Toast)>,
+    time: Res<Time>,
+) {
+    for (toast_entity, mut toast) in toast_query.iter_mut() {
+        // Real time, so toasts still go away while the game is paused.
+        toast.timer.tick(time.raw_delta());
+        if toast.timer.finished() {
+            commands.entity(toast_entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+use crate::{
+    achievements::resources::{AchievementDefinitions, RunProgress, UnlockedAchievements},
+    events::{AchievementUnlocked, NearMiss, StarCollected, WaveStarted},
+    game::score::resources::{Combo, Score},
+};
+
+pub fn reset_run_progress(mut run_progress: ResMut<RunProgress>) {
+    *run_progress = RunProgress::default();
+}
+
+pub fn track_run_progress(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut near_miss_event_reader: EventReader<NearMiss>,
+    mut wave_started_event_reader: EventReader<WaveStarted>,
+    mut run_progress: ResMut<RunProgress>,
+    combo: Option<Res<Combo>>,
+    time: Res<Time>,
+) {
+    run_progress.time_alive += time.delta_seconds();
+    run_progress.stars += star_collected_event_reader.iter().count() as u32;
+    run_progress.near_misses += near_miss_event_reader.iter().count() as u32;
+    if let Some(event) = wave_started_event_reader.iter().last() {
+        run_progress.waves = run_progress.waves.max(event.wave);
+    }
+    if let Some(combo) = combo {
+        run_progress.best_combo = run_progress.best_combo.max(combo.count);
+    }
+}
+
+pub fn evaluate_achievements(
+    achievement_definitions: Res<AchievementDefinitions>,
+    mut unlocked_achievements: ResMut<UnlockedAchievements>,
+    mut achievement_unlocked_event_writer: EventWriter<AchievementUnlocked>,
+    run_progress: Res<RunProgress>,
+    score: Option<Res<Score>>,
+) {
+    let score = score.map_or(0, |score| score.value);
+
+    for definition in achievement_definitions.definitions.iter() {
+        if unlocked_achievements.contains(&definition.id)
+            || !definition.condition.is_met(&run_progress, score)
+        {
+            continue;
+        }
+
+        println!("Achievement unlocked: {}", definition.name);
+        unlocked_achievements.ids.push(definition.id.clone());
+        unlocked_achievements.save();
+        achievement_unlocked_event_writer.send(AchievementUnlocked {
+            name: definition.name.clone(),
+            description: definition.description.clone(),
+        });
+    }
+}
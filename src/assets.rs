@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use bevy::asset::HandleId;
+use bevy::prelude::*;
+
+use crate::config::GameConfig;
+use crate::game::enemy::components::EnemyKind;
+use crate::game::star::components::StarKind;
+
+const FONT_PATH: &str = "fonts/FiraSans-Bold.ttf";
+const PLAYER_SPRITE_PATH: &str = "sprites/ball_blue_large.png";
+
+// Every sprite, sound and font the game uses, started loading up front so the
+// loading screen can wait on them and nothing is fetched mid-game.
+#[derive(Resource)]
+pub struct GameAssets {
+    pub font: Handle<Font>,
+    pub player: Handle<Image>,
+    pub enemies: HashMap<EnemyKind, Handle<Image>>,
+    pub stars: HashMap<StarKind, Handle<Image>>,
+    pub bounce: Vec<Handle<AudioSource>>,
+    pub pickup: Handle<AudioSource>,
+    pub death: Handle<AudioSource>,
+    // Keyed by track path. Optional; a missing track just plays nothing.
+    pub music: HashMap<String, Handle<AudioSource>>,
+}
+
+impl FromWorld for GameAssets {
+    fn from_world(world: &mut World) -> GameAssets {
+        let asset_server = world.resource::<AssetServer>();
+        let game_config = world.resource::<GameConfig>();
+
+        GameAssets {
+            font: asset_server.load(FONT_PATH),
+            player: asset_server.load(PLAYER_SPRITE_PATH),
+            enemies: EnemyKind::ALL
+                .iter()
+                .map(|kind| (*kind, asset_server.load(kind.sprite())))
+                .collect(),
+            stars: StarKind::ALL
+                .iter()
+                .map(|kind| {
+                    let sprite = game_config.stars.variant(*kind).sprite.as_str();
+                    (*kind, asset_server.load(sprite))
+                })
+                .collect(),
+            bounce: ["audio/pluck_001.ogg", "audio/pluck_002.ogg"]
+                .iter()
+                .map(|path| asset_server.load(*path))
+                .collect(),
+            pickup: asset_server.load("audio/laserLarge_000.ogg"),
+            death: asset_server.load("audio/explosionCrunch_000.ogg"),
+            music: [&game_config.music.menu_track, &game_config.music.game_track]
+                .into_iter()
+                .flatten()
+                .map(|path| (path.clone(), asset_server.load(path.as_str())))
+                .collect(),
+        }
+    }
+}
+
+impl GameAssets {
+    pub fn enemy(&self, kind: EnemyKind) -> Handle<Image> {
+        self.enemies[&kind].clone()
+    }
+
+    pub fn star(&self, kind: StarKind) -> Handle<Image> {
+        self.stars[&kind].clone()
+    }
+
+    pub fn music(&self, path: &str) -> Option<Handle<AudioSource>> {
+        self.music.get(path).cloned()
+    }
+
+    // Everything the loading screen waits on; the game still runs without them,
+    // but with holes in it.
+    pub fn required(&self) -> Vec<HandleId> {
+        let mut handles = vec![self.font.id(), self.player.id()];
+        handles.extend(self.enemies.values().map(|handle| handle.id()));
+        handles.extend(self.stars.values().map(|handle| handle.id()));
+        handles.extend(self.bounce.iter().map(|handle| handle.id()));
+        handles.extend([self.pickup.id(), self.death.id()]);
+        handles
+    }
+
+    pub fn optional(&self) -> Vec<HandleId> {
+        self.music.values().map(|handle| handle.id()).collect()
+    }
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+pub const MAX_BOUNCE_SOUNDS_PER_FRAME: usize = 3;
+
+// Named so it doesn't shadow bevy's own `AudioPlugin`.
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SoundEffects>()
+            .init_resource::<MusicPlayer>()
+            .add_systems((
+                play_bounce_sounds,
+                play_pickup_sounds,
+                play_death_sounds,
+                play_wave_sounds,
+            ))
+            .add_systems((select_music_track, fade_music.after(select_music_track)));
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use crate::assets::GameAssets;
+use crate::settings::resources::{Settings, VolumeChannel};
+
+// How a single kind of sound is played: which files it may pick from, how loud
+// it is relative to its channel and how far its pitch may wander.
+pub struct SoundEffect {
+    pub variants: Vec<Handle<AudioSource>>,
+    pub channel: VolumeChannel,
+    pub volume: f32,
+    pub pitch: f32,
+    pub pitch_variation: f32,
+}
+
+impl SoundEffect {
+    fn new(
+        variants: Vec<Handle<AudioSource>>,
+        volume: f32,
+        pitch: f32,
+        pitch_variation: f32,
+    ) -> SoundEffect {
+        SoundEffect {
+            variants,
+            channel: VolumeChannel::Sfx,
+            volume,
+            pitch,
+            pitch_variation,
+        }
+    }
+
+    pub fn play(&self, audio: &Audio, settings: &Settings) {
+        let Some(variant) = self.variants.choose(&mut thread_rng()) else {
+            return;
+        };
+        let volume = settings.master_volume * settings.volume(self.channel) * self.volume;
+        let speed = self.pitch + (random::<f32>() * 2.0 - 1.0) * self.pitch_variation;
+
+        audio.play_with_settings(
+            variant.clone(),
+            PlaybackSettings::ONCE.with_volume(volume).with_speed(speed),
+        );
+    }
+}
+
+#[derive(Resource)]
+pub struct SoundEffects {
+    pub bounce: SoundEffect,
+    pub pickup: SoundEffect,
+    pub death: SoundEffect,
+    pub wave_start: SoundEffect,
+}
+
+impl FromWorld for SoundEffects {
+    fn from_world(world: &mut World) -> SoundEffects {
+        let game_assets = world.resource::<GameAssets>();
+
+        SoundEffects {
+            bounce: SoundEffect::new(game_assets.bounce.clone(), 0.5, 1.0, 0.15),
+            pickup: SoundEffect::new(vec![game_assets.pickup.clone()], 1.0, 1.0, 0.1),
+            death: SoundEffect::new(vec![game_assets.death.clone()], 1.0, 1.0, 0.05),
+            // Slowed right down so it reads as a low warning tone.
+            wave_start: SoundEffect::new(game_assets.bounce[..1].to_vec(), 0.8, 0.5, 0.0),
+        }
+    }
+}
+
+pub struct MusicTrack {
+    pub path: String,
+    pub sink: Handle<AudioSink>,
+    // 0.0 is silent, 1.0 is full volume; moved towards the target by the crossfade.
+    pub fade: f32,
+}
+
+#[derive(Resource)]
+pub struct MusicPlayer {
+    pub current: Option<MusicTrack>,
+    pub fading_out: Vec<MusicTrack>,
+    // Eased towards the paused volume rather than snapping to it.
+    pub duck: f32,
+}
+
+impl Default for MusicPlayer {
+    fn default() -> MusicPlayer {
+        MusicPlayer {
+            current: None,
+            fading_out: Vec::new(),
+            duck: 1.0,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use super::resources::*;
+use super::MAX_BOUNCE_SOUNDS_PER_FRAME;
+use crate::assets::GameAssets;
+use crate::config::GameConfig;
+use crate::events::{EnemyBounced, PlayerHit, StarCollected, WaveStarted};
+use crate::game::SimulationState;
+use crate::settings::resources::Settings;
+use crate::AppState;
+
+pub fn play_bounce_sounds(
+    mut enemy_bounced_event_reader: EventReader<EnemyBounced>,
+    sound_effects: Res<SoundEffects>,
+    settings: Res<Settings>,
+    audio: Res<Audio>,
+) {
+    // With a full arena several balls hit a wall on the same frame; a handful of
+    // plucks sounds the same as dozens without turning into noise.
+    let bounces = enemy_bounced_event_reader.iter().count();
+    for _ in 0..bounces.min(MAX_BOUNCE_SOUNDS_PER_FRAME) {
+        sound_effects.bounce.play(&audio, &settings);
+    }
+}
+
+pub fn play_pickup_sounds(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    sound_effects: Res<SoundEffects>,
+    settings: Res<Settings>,
+    audio: Res<Audio>,
+) {
+    for _ in star_collected_event_reader.iter() {
+        sound_effects.pickup.play(&audio, &settings);
+    }
+}
+
+pub fn play_death_sounds(
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    sound_effects: Res<SoundEffects>,
+    settings: Res<Settings>,
+    audio: Res<Audio>,
+) {
+    for _ in player_hit_event_reader.iter() {
+        sound_effects.death.play(&audio, &settings);
+    }
+}
+
+pub fn play_wave_sounds(
+    mut wave_started_event_reader: EventReader<WaveStarted>,
+    sound_effects: Res<SoundEffects>,
+    settings: Res<Settings>,
+    audio: Res<Audio>,
+) {
+    for _ in wave_started_event_reader.iter() {
+        sound_effects.wave_start.play(&audio, &settings);
+    }
+}
+
+pub fn select_music_track(
+    app_state: Res<State<AppState>>,
+    game_config: Res<GameConfig>,
+    mut music_player: ResMut<MusicPlayer>,
+    game_assets: Res<GameAssets>,
+    audio: Res<Audio>,
+    audio_sinks: Res<Assets<AudioSink>>,
+) {
+    if !app_state.is_changed() {
+        return;
+    }
+
+    let track = game_config.music.track_for(app_state.0);
+    let current_path = music_player.current.as_ref().map(|current| &current.path);
+    if track == current_path {
+        return;
+    }
+
+    if let Some(current) = music_player.current.take() {
+        music_player.fading_out.push(current);
+    }
+    music_player.current = track.and_then(|path| {
+        let sink = audio.play_with_settings(
+            game_assets.music(path)?,
+            PlaybackSettings::LOOP.with_volume(0.0),
+        );
+        Some(MusicTrack {
+            path: path.clone(),
+            // `play` only hands back a weak handle; keep it alive or the sink is dropped.
+            sink: audio_sinks.get_handle(sink),
+            fade: 0.0,
+        })
+    });
+}
+
+pub fn fade_music(
+    mut music_player: ResMut<MusicPlayer>,
+    game_config: Res<GameConfig>,
+    settings: Res<Settings>,
+    app_state: Res<State<AppState>>,
+    simulation_state: Res<State<SimulationState>>,
+    audio_sinks: Res<Assets<AudioSink>>,
+    time: Res<Time>,
+) {
+    let music_config = &game_config.music;
+    let step = if music_config.crossfade_seconds > 0.0 {
+        time.delta_seconds() / music_config.crossfade_seconds
+    } else {
+        1.0
+    };
+
+    let paused = app_state.0 == AppState::Game && simulation_state.0 == SimulationState::Paused;
+    let target_duck = if paused {
+        music_config.paused_volume
+    } else {
+        1.0
+    };
+    let duck = music_player.duck + (target_duck - music_player.duck).clamp(-step, step);
+    music_player.duck = duck;
+
+    let volume = settings.master_volume * settings.music_volume * duck;
+
+    if let Some(current) = music_player.current.as_mut() {
+        current.fade = (current.fade + step).min(1.0);
+        if let Some(sink) = audio_sinks.get(&current.sink) {
+            sink.set_volume(current.fade * volume);
+        }
+    }
+
+    music_player.fading_out.retain_mut(|track| {
+        track.fade -= step;
+        let Some(sink) = audio_sinks.get(&track.sink) else {
+            return track.fade > 0.0;
+        };
+        if track.fade > 0.0 {
+            sink.set_volume(track.fade * volume);
+            true
+        } else {
+            sink.stop();
+            false
+        }
+    });
+}
@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct HitStopSystemSet;
+
+pub struct CameraEffectsPlugin;
+
+impl Plugin for CameraEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraEffects>()
+            .init_resource::<HitStop>()
+            .add_systems((add_trauma, start_zoom_pulse))
+            .add_systems(
+                (tick_hit_stop, start_hit_stop)
+                    .chain()
+                    .in_set(HitStopSystemSet),
+            )
+            .add_system(
+                apply_camera_effects
+                    .after(add_trauma)
+                    .after(start_zoom_pulse),
+            );
+    }
+}
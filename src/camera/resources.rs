@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct CameraEffects {
+    pub trauma: f32,
+    // Where the camera sits before any shake is added on top.
+    pub centre: Vec2,
+    // 1.0 right after a combo milestone, easing back to 0.0.
+    pub zoom_pulse: f32,
+}
+
+impl CameraEffects {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct HitStop {
+    pub remaining: f32,
+}
+
+impl HitStop {
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0.0
+    }
+}
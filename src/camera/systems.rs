@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use super::resources::*;
+use crate::config::GameConfig;
+use crate::events::{ComboReached, EnemyBounced, PlayerHit, StarCollected};
+use crate::game::network::LOCAL_PLAYER_ID;
+use crate::settings::resources::Settings;
+
+pub fn add_trauma(
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut enemy_bounced_event_reader: EventReader<EnemyBounced>,
+    mut camera_effects: ResMut<CameraEffects>,
+    game_config: Res<GameConfig>,
+    settings: Res<Settings>,
+) {
+    let camera_config = &game_config.camera;
+    let trauma = player_hit_event_reader.iter().count() as f32 * camera_config.death_trauma
+        + star_collected_event_reader.iter().count() as f32 * camera_config.star_trauma
+        + enemy_bounced_event_reader.iter().count() as f32 * camera_config.bounce_trauma;
+
+    if settings.camera_effects && trauma > 0.0 {
+        camera_effects.add_trauma(trauma);
+    }
+}
+
+pub fn start_zoom_pulse(
+    mut combo_reached_event_reader: EventReader<ComboReached>,
+    mut camera_effects: ResMut<CameraEffects>,
+    settings: Res<Settings>,
+) {
+    if combo_reached_event_reader.iter().count() > 0 && settings.camera_effects {
+        camera_effects.zoom_pulse = 1.0;
+    }
+}
+
+pub fn start_hit_stop(
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut hit_stop: ResMut<HitStop>,
+    mut time: ResMut<Time>,
+    game_config: Res<GameConfig>,
+    settings: Res<Settings>,
+) {
+    let local_player_hit = player_hit_event_reader
+        .iter()
+        .any(|event| event.player_id == LOCAL_PLAYER_ID);
+
+    if local_player_hit && settings.camera_effects && game_config.camera.hit_stop_seconds > 0.0 {
+        hit_stop.remaining = game_config.camera.hit_stop_seconds;
+        time.pause();
+    }
+}
+
+pub fn tick_hit_stop(mut hit_stop: ResMut<HitStop>, mut time: ResMut<Time>) {
+    if hit_stop.is_active() {
+        // Game time is frozen, so count down in real time.
+        hit_stop.remaining -= time.raw_delta_seconds();
+        if !hit_stop.is_active() {
+            time.unpause();
+        }
+    }
+}
+
+pub fn apply_camera_effects(
+    mut camera_query: Query<(&mut Transform, &mut OrthographicProjection), With<Camera2d>>,
+    mut camera_effects: ResMut<CameraEffects>,
+    game_config: Res<GameConfig>,
+    time: Res<Time>,
+) {
+    let camera_config = &game_config.camera;
+    // Real time so the shake keeps going through a hit-stop.
+    let delta_seconds = time.raw_delta_seconds();
+
+    camera_effects.trauma =
+        (camera_effects.trauma - camera_config.trauma_decay * delta_seconds).max(0.0);
+    if camera_config.zoom_pulse_seconds > 0.0 {
+        camera_effects.zoom_pulse =
+            (camera_effects.zoom_pulse - delta_seconds / camera_config.zoom_pulse_seconds).max(0.0);
+    } else {
+        camera_effects.zoom_pulse = 0.0;
+    }
+
+    let shake = camera_effects.trauma * camera_effects.trauma;
+    let shake_offset = Vec2::new(random::<f32>() * 2.0 - 1.0, random::<f32>() * 2.0 - 1.0)
+        * camera_config.max_shake_offset
+        * shake;
+
+    if let Ok((mut camera_transform, mut projection)) = camera_query.get_single_mut() {
+        let position = camera_effects.centre + shake_offset;
+        camera_transform.translation = position.extend(camera_transform.translation.z);
+        projection.scale = 1.0 - camera_config.zoom_pulse_amount * camera_effects.zoom_pulse;
+    }
+}
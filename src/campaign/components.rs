@@ -0,0 +1,21 @@
+use bevy::prelude::Component;
+
+use crate::game::mode::resources::GameMode;
+
+#[derive(Component)]
+pub struct LevelSelectMenu {}
+#[derive(Component)]
+pub struct BackButton {}
+
+// Picks a mode for a free run of the configured level, then on to the mutators.
+#[derive(Component)]
+pub struct ModeButton {
+    pub mode: GameMode,
+}
+
+// One per campaign level, locked or not.
+#[derive(Component)]
+pub struct LevelButton {
+    pub index: usize,
+    pub unlocked: bool,
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::systems::{
+    interactions::{
+        interact_with_back_button, interact_with_level_buttons, interact_with_mode_buttons,
+    },
+    layout::{despawn_level_select, spawn_level_select},
+    progress::{clear_campaign_run, record_campaign_result, return_to_level_select},
+};
+use resources::{CampaignDefinition, CampaignProgress, CampaignRun};
+
+mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+pub const CAMPAIGN_PATH: &str = "config/campaign.ron";
+
+pub struct CampaignPlugin;
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(CampaignDefinition::load())
+            .insert_resource(CampaignProgress::load())
+            .init_resource::<CampaignRun>()
+            .add_system(record_campaign_result)
+            .add_system(return_to_level_select.in_schedule(OnEnter(AppState::GameOver)))
+            .add_system(clear_campaign_run.in_schedule(OnEnter(AppState::MainMenu)))
+            .add_system(spawn_level_select.in_schedule(OnEnter(AppState::LevelSelect)))
+            .add_systems(
+                (
+                    interact_with_level_buttons,
+                    interact_with_mode_buttons,
+                    interact_with_back_button,
+                )
+                    .in_set(OnUpdate(AppState::LevelSelect)),
+            )
+            .add_system(despawn_level_select.in_schedule(OnExit(AppState::LevelSelect)));
+    }
+}
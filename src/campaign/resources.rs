@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::CAMPAIGN_PATH;
+use crate::config::load_data_file;
+use crate::game::level::loader::WinCondition;
+use crate::persistence;
+
+pub const CAMPAIGN_PROGRESS_FILE: &str = "campaign.ron";
+pub const MAX_RATING: u8 = 3;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CampaignLevel {
+    pub name: String,
+    // Level asset under `assets/`.
+    pub level: String,
+    // Replaces the level's own win condition while playing it in the campaign.
+    pub objective: WinCondition,
+    // Completing is worth one star; these scores earn the second and third.
+    pub star_scores: (u32, u32),
+}
+
+impl CampaignLevel {
+    pub fn rating(&self, score: u32) -> u8 {
+        1 + u8::from(score >= self.star_scores.0) + u8::from(score >= self.star_scores.1)
+    }
+}
+
+// The levels in the order they unlock.
+#[derive(Resource, Deserialize, Debug, Default)]
+#[serde(transparent)]
+pub struct CampaignDefinition {
+    pub levels: Vec<CampaignLevel>,
+}
+
+impl CampaignDefinition {
+    pub fn load() -> CampaignDefinition {
+        load_data_file(CAMPAIGN_PATH)
+    }
+}
+
+// Best rating per level, keyed by level path so reordering the campaign keeps it.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct CampaignProgress {
+    pub ratings: BTreeMap<String, u8>,
+}
+
+impl CampaignProgress {
+    pub fn load() -> CampaignProgress {
+        persistence::load_or_default(CAMPAIGN_PROGRESS_FILE)
+    }
+
+    pub fn save(&self) {
+        persistence::save(CAMPAIGN_PROGRESS_FILE, self);
+    }
+
+    pub fn rating(&self, level: &CampaignLevel) -> u8 {
+        self.ratings.get(&level.level).copied().unwrap_or(0)
+    }
+
+    // The first level is always open; each later one needs the one before it.
+    pub fn is_unlocked(&self, campaign_definition: &CampaignDefinition, index: usize) -> bool {
+        index == 0
+            || campaign_definition
+                .levels
+                .get(index - 1)
+                .is_some_and(|previous| self.rating(previous) > 0)
+    }
+
+    // Returns true when this beat the previous best.
+    pub fn record(&mut self, level: &CampaignLevel, rating: u8) -> bool {
+        if rating <= self.rating(level) {
+            return false;
+        }
+        self.ratings.insert(level.level.clone(), rating);
+        true
+    }
+}
+
+// Which campaign level is being played, if any.
+#[derive(Resource, Default)]
+pub struct CampaignRun {
+    pub level: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::asset_path;
+
+    fn campaign_level(star_scores: (u32, u32)) -> CampaignLevel {
+        CampaignLevel {
+            name: "Test".to_string(),
+            level: "levels/open.level.ron".to_string(),
+            objective: WinCondition::Score(10),
+            star_scores,
+        }
+    }
+
+    #[test]
+    fn rating_counts_star_scores_reached() {
+        let level = campaign_level((100, 200));
+
+        assert_eq!(level.rating(0), 1);
+        assert_eq!(level.rating(99), 1);
+        assert_eq!(level.rating(100), 2);
+        assert_eq!(level.rating(199), 2);
+        assert_eq!(level.rating(200), MAX_RATING);
+        assert_eq!(level.rating(u32::MAX), MAX_RATING);
+    }
+
+    #[test]
+    fn shipped_campaign_is_consistent() {
+        let campaign_definition = CampaignDefinition::load();
+        assert!(!campaign_definition.levels.is_empty());
+
+        for level in campaign_definition.levels.iter() {
+            assert!(level.star_scores.0 <= level.star_scores.1, "{}", level.name);
+            assert!(asset_path(&level.level).exists(), "{}", level.level);
+        }
+    }
+}
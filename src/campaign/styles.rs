@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+pub const LOCKED_BUTTON_COLOUR: Color = Color::rgb(0.08, 0.08, 0.08);
+pub const LOCKED_TEXT_COLOUR: Color = Color::rgb(0.4, 0.4, 0.4);
+pub const RATING_COLOUR: Color = Color::rgb(1.0, 0.85, 0.2);
+
+pub const LEVEL_BUTTON_STYLE: Style = Style {
+    flex_direction: FlexDirection::Row,
+    justify_content: JustifyContent::SpaceBetween,
+    align_items: AlignItems::Center,
+    padding: UiRect::new(Val::Px(16.), Val::Px(16.), Val::Px(0.), Val::Px(0.)),
+    size: Size::new(Val::Px(720.), Val::Px(64.)),
+    ..Style::DEFAULT
+};
+
+pub const LEVEL_TEXT_STYLE: Style = Style {
+    flex_direction: FlexDirection::Column,
+    ..Style::DEFAULT
+};
+
+pub fn get_level_name_text_style(game_assets: &GameAssets, colour: Color) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 28.,
+        color: colour,
+    }
+}
+
+pub fn get_objective_text_style(game_assets: &GameAssets, colour: Color) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 18.,
+        color: colour,
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use crate::{
+    campaign::{
+        components::{BackButton, LevelButton, ModeButton},
+        resources::{CampaignDefinition, CampaignRun},
+    },
+    config::GameConfig,
+    game::{level::resources::CurrentLevel, mode::resources::GameMode},
+    main_menu::{
+        components::ButtonQuery,
+        styles::{HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOUR, PRESSED_BUTTON_COLOR},
+    },
+    AppState,
+};
+
+pub fn interact_with_level_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &LevelButton),
+        Changed<Interaction>,
+    >,
+    campaign_definition: Res<CampaignDefinition>,
+    mut campaign_run: ResMut<CampaignRun>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut game_mode: ResMut<GameMode>,
+    asset_server: Res<AssetServer>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut background_colour, level_button) in button_query.iter_mut() {
+        // Locked levels keep their dimmed colour and ignore clicks.
+        if !level_button.unlocked {
+            continue;
+        }
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                let level = &campaign_definition.levels[level_button.index];
+                *current_level = CurrentLevel {
+                    handle: Some(asset_server.load(level.level.as_str())),
+                    objective: Some(level.objective),
+                };
+                // The objective decides when a campaign level is won.
+                *game_mode = GameMode::Endless;
+                campaign_run.level = Some(level_button.index);
+                app_state_next_state.set(AppState::Game);
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_mode_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &ModeButton),
+        Changed<Interaction>,
+    >,
+    mut campaign_run: ResMut<CampaignRun>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut game_mode: ResMut<GameMode>,
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut background_colour, mode_button) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                *current_level = CurrentLevel::from_config(&asset_server, &game_config);
+                *game_mode = mode_button.mode;
+                campaign_run.level = None;
+                app_state_next_state.set(AppState::Mutators);
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: ButtonQuery<BackButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::MainMenu)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
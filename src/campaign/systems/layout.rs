@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets::GameAssets,
+    campaign::{
+        components::{BackButton, LevelButton, LevelSelectMenu, ModeButton},
+        resources::{CampaignDefinition, CampaignProgress, MAX_RATING},
+        styles::{
+            get_level_name_text_style, get_objective_text_style, LEVEL_BUTTON_STYLE,
+            LEVEL_TEXT_STYLE, LOCKED_BUTTON_COLOUR, LOCKED_TEXT_COLOUR, RATING_COLOUR,
+        },
+    },
+    game::mode::resources::GameMode,
+    main_menu::styles::{
+        get_button_text_style, get_title_text_style, BUTTON_ROW_STYLE, BUTTON_STYLE,
+        MAIN_MENU_STYLE, NORMAL_BUTTON_COLOUR,
+    },
+};
+
+pub fn spawn_level_select(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    campaign_definition: Res<CampaignDefinition>,
+    campaign_progress: Res<CampaignProgress>,
+) {
+    build_level_select(
+        &mut commands,
+        &game_assets,
+        &campaign_definition,
+        &campaign_progress,
+    );
+}
+
+pub fn despawn_level_select(
+    mut commands: Commands,
+    level_select_query: Query<Entity, With<LevelSelectMenu>>,
+) {
+    if let Ok(level_select_entity) = level_select_query.get_single() {
+        commands.entity(level_select_entity).despawn_recursive();
+    }
+}
+
+pub fn build_level_select(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    campaign_definition: &CampaignDefinition,
+    campaign_progress: &CampaignProgress,
+) -> Entity {
+    commands
+        .spawn((
+            NodeBundle {
+                style: MAIN_MENU_STYLE,
+                ..default()
+            },
+            LevelSelectMenu {},
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn(TextBundle::from_section(
+                "Campaign",
+                get_title_text_style(game_assets),
+            ));
+            // Levels
+            for (index, level) in campaign_definition.levels.iter().enumerate() {
+                let unlocked = campaign_progress.is_unlocked(campaign_definition, index);
+                let (background_colour, text_colour) = if unlocked {
+                    (NORMAL_BUTTON_COLOUR, Color::WHITE)
+                } else {
+                    (LOCKED_BUTTON_COLOUR, LOCKED_TEXT_COLOUR)
+                };
+                let rating = campaign_progress.rating(level);
+                let rating_label = if unlocked {
+                    format!("{} / {} stars", rating, MAX_RATING)
+                } else {
+                    "Locked".to_string()
+                };
+
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: LEVEL_BUTTON_STYLE,
+                            background_color: background_colour.into(),
+                            ..default()
+                        },
+                        LevelButton { index, unlocked },
+                    ))
+                    .with_children(|parent| {
+                        parent
+                            .spawn(NodeBundle {
+                                style: LEVEL_TEXT_STYLE,
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle::from_section(
+                                    format!("{}. {}", index + 1, level.name),
+                                    get_level_name_text_style(game_assets, text_colour),
+                                ));
+                                parent.spawn(TextBundle::from_section(
+                                    level.objective.description(),
+                                    get_objective_text_style(game_assets, text_colour),
+                                ));
+                            });
+                        parent.spawn(TextBundle::from_section(
+                            rating_label,
+                            get_level_name_text_style(
+                                game_assets,
+                                if rating > 0 {
+                                    RATING_COLOUR
+                                } else {
+                                    text_colour
+                                },
+                            ),
+                        ));
+                    });
+            }
+            // Modes
+            parent
+                .spawn(NodeBundle {
+                    style: BUTTON_ROW_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for mode in GameMode::FREE_PLAY {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: BUTTON_STYLE,
+                                    background_color: NORMAL_BUTTON_COLOUR.into(),
+                                    ..default()
+                                },
+                                ModeButton { mode },
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle::from_section(
+                                    mode.name(),
+                                    get_button_text_style(game_assets),
+                                ));
+                            });
+                    }
+                });
+            // Back
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    BackButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Back",
+                        get_button_text_style(game_assets),
+                    ));
+                });
+        })
+        .id()
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::{
+    campaign::resources::{CampaignDefinition, CampaignProgress, CampaignRun, MAX_RATING},
+    events::LevelCompleted,
+    AppState,
+};
+
+pub fn record_campaign_result(
+    mut level_completed_event_reader: EventReader<LevelCompleted>,
+    campaign_run: Res<CampaignRun>,
+    campaign_definition: Res<CampaignDefinition>,
+    mut campaign_progress: ResMut<CampaignProgress>,
+) {
+    for event in level_completed_event_reader.iter() {
+        let Some(level) = campaign_run
+            .level
+            .and_then(|index| campaign_definition.levels.get(index))
+        else {
+            continue;
+        };
+
+        let rating = level.rating(event.score);
+        println!("{} complete: {} / {} stars", level.name, rating, MAX_RATING);
+        if campaign_progress.record(level, rating) {
+            campaign_progress.save();
+        }
+    }
+}
+
+// Campaign runs end on the level select screen, win or lose.
+pub fn return_to_level_select(
+    campaign_run: Res<CampaignRun>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if campaign_run.level.is_some() {
+        app_state_next_state.set(AppState::LevelSelect);
+    }
+}
+
+pub fn clear_campaign_run(mut campaign_run: ResMut<CampaignRun>) {
+    campaign_run.level = None;
+}
@@ -0,0 +1,483 @@
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::asset::FileAssetIo;
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::game::enemy::components::EnemyKind;
+use crate::game::enemy::ENEMY_SPEED;
+use crate::game::player::PLAYER_SPEED;
+use crate::game::star::components::StarKind;
+use crate::AppState;
+
+// Designer-facing tuning that ships with the game, as opposed to `Settings`
+// which belong to the player and live in the save directory.
+pub const CONFIG_PATH: &str = "config/game.ron";
+
+#[derive(Resource, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct GameConfig {
+    pub music: MusicConfig,
+    pub particles: ParticlesConfig,
+    pub camera: CameraConfig,
+    pub stars: StarsConfig,
+    pub player: PlayerConfig,
+    pub enemies: EnemiesConfig,
+    // Level asset under `assets/`, e.g. "levels/pillars.level.ron". `None`
+    // plays the default open arena.
+    pub level: Option<String>,
+}
+
+impl GameConfig {
+    pub fn load() -> GameConfig {
+        load_data_file(CONFIG_PATH)
+    }
+}
+
+// Where a path under `assets/` is on disk, found the same way the asset server
+// finds it rather than from the working directory.
+pub fn asset_path(path: &str) -> PathBuf {
+    FileAssetIo::get_base_path().join("assets").join(path)
+}
+
+// Reads one of the RON data files shipped under `assets/`, falling back to the
+// default so a broken file is reported instead of stopping the game.
+pub fn load_data_file<T: DeserializeOwned + Default>(path: &str) -> T {
+    let Ok(contents) = fs::read_to_string(asset_path(path)) else {
+        println!("No {} found, using defaults", path);
+        return T::default();
+    };
+
+    match ron::from_str(&contents) {
+        Ok(value) => value,
+        Err(error) => {
+            println!("Could not read {}: {}", path, error);
+            T::default()
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MusicConfig {
+    // Paths relative to `assets/`. `None` means silence in that state.
+    pub menu_track: Option<String>,
+    pub game_track: Option<String>,
+    pub crossfade_seconds: f32,
+    // Music volume multiplier while the simulation is paused.
+    pub paused_volume: f32,
+}
+
+impl Default for MusicConfig {
+    fn default() -> MusicConfig {
+        MusicConfig {
+            menu_track: Some("audio/music/menu_theme.wav".to_string()),
+            game_track: Some("audio/music/game_theme.wav".to_string()),
+            crossfade_seconds: 1.5,
+            paused_volume: 0.3,
+        }
+    }
+}
+
+impl MusicConfig {
+    pub fn track_for(&self, app_state: AppState) -> Option<&String> {
+        match app_state {
+            AppState::Game => self.game_track.as_ref(),
+            _ => self.menu_track.as_ref(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ParticlesConfig {
+    // Particles are recycled from a fixed pool; bursts past this are dropped.
+    pub pool_size: usize,
+    pub star_collected: EmitterConfig,
+    pub enemy_bounced: EmitterConfig,
+    pub player_death: EmitterConfig,
+}
+
+impl Default for ParticlesConfig {
+    fn default() -> ParticlesConfig {
+        ParticlesConfig {
+            pool_size: 512,
+            star_collected: EmitterConfig {
+                count: 16,
+                speed: (80.0, 220.0),
+                lifetime: (0.3, 0.6),
+                start_colour: (1.0, 0.85, 0.2, 1.0),
+                end_colour: (1.0, 0.5, 0.0, 0.0),
+                ..default()
+            },
+            enemy_bounced: EmitterConfig {
+                count: 6,
+                speed: (120.0, 260.0),
+                lifetime: (0.1, 0.25),
+                start_size: 4.0,
+                end_size: 1.0,
+                spread: 90.0,
+                ..default()
+            },
+            player_death: EmitterConfig {
+                count: 64,
+                speed: (60.0, 420.0),
+                lifetime: (0.5, 1.2),
+                start_size: 12.0,
+                end_size: 2.0,
+                start_colour: (1.0, 0.6, 0.2, 1.0),
+                end_colour: (0.4, 0.1, 0.1, 0.0),
+                drag: 2.0,
+                ..default()
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct EmitterConfig {
+    pub count: usize,
+    // Each particle picks uniformly from these (min, max) ranges.
+    pub speed: (f32, f32),
+    pub lifetime: (f32, f32),
+    pub start_size: f32,
+    pub end_size: f32,
+    pub start_colour: (f32, f32, f32, f32),
+    pub end_colour: (f32, f32, f32, f32),
+    // Cone in degrees around the emit direction; 360 is a full ring.
+    pub spread: f32,
+    // Fraction of velocity lost per second.
+    pub drag: f32,
+}
+
+impl Default for EmitterConfig {
+    fn default() -> EmitterConfig {
+        EmitterConfig {
+            count: 8,
+            speed: (50.0, 150.0),
+            lifetime: (0.3, 0.6),
+            start_size: 6.0,
+            end_size: 0.0,
+            start_colour: (1.0, 1.0, 1.0, 1.0),
+            end_colour: (1.0, 1.0, 1.0, 0.0),
+            spread: 360.0,
+            drag: 0.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct CameraConfig {
+    // Trauma is 0..1; the shake offset scales with trauma squared.
+    pub max_shake_offset: f32,
+    pub trauma_decay: f32,
+    pub death_trauma: f32,
+    pub star_trauma: f32,
+    pub bounce_trauma: f32,
+    pub hit_stop_seconds: f32,
+    // How far the camera zooms in on a combo milestone, as a fraction of the view.
+    pub zoom_pulse_amount: f32,
+    pub zoom_pulse_seconds: f32,
+}
+
+impl Default for CameraConfig {
+    fn default() -> CameraConfig {
+        CameraConfig {
+            max_shake_offset: 24.0,
+            trauma_decay: 1.5,
+            death_trauma: 0.8,
+            star_trauma: 0.1,
+            bounce_trauma: 0.0,
+            hit_stop_seconds: 0.25,
+            zoom_pulse_amount: 0.04,
+            zoom_pulse_seconds: 0.35,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct PlayerConfig {
+    // Without momentum the ball moves at `max_speed` the moment a key is held
+    // and stops dead when it is released.
+    pub momentum: bool,
+    pub acceleration: f32,
+    // Fraction of velocity lost per second.
+    pub drag: f32,
+    pub max_speed: f32,
+    // Fraction of speed kept when bouncing off the edge of the arena.
+    pub wall_bounciness: f32,
+    pub dash: DashConfig,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> PlayerConfig {
+        PlayerConfig {
+            momentum: true,
+            acceleration: 2000.0,
+            drag: 2.5,
+            max_speed: PLAYER_SPEED,
+            wall_bounciness: 0.6,
+            dash: DashConfig::default(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct DashConfig {
+    pub speed: f32,
+    // Seconds the burst lasts, and how long enemies pass through the player;
+    // the latter is usually a little longer so a dash can't end inside a ball.
+    pub duration: f32,
+    pub invulnerability: f32,
+    pub cooldown: f32,
+}
+
+impl Default for DashConfig {
+    fn default() -> DashConfig {
+        DashConfig {
+            speed: 1400.0,
+            duration: 0.15,
+            invulnerability: 0.3,
+            cooldown: 2.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct EnemiesConfig {
+    pub bouncer: EnemyKindConfig,
+}
+
+impl EnemiesConfig {
+    pub fn kind(&self, kind: EnemyKind) -> &EnemyKindConfig {
+        match kind {
+            EnemyKind::Bouncer => &self.bouncer,
+        }
+    }
+}
+
+// How enemies of one kind set off when they spawn without a level-given
+// direction or speed.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct EnemyKindConfig {
+    // Each enemy picks uniformly from this (min, max) range.
+    pub speed: (f32, f32),
+    // Chance of heading for the player rather than a random direction.
+    pub aimed_chance: f32,
+    // Degrees either side of the player an aimed enemy may be off by.
+    pub aim_spread: f32,
+}
+
+impl Default for EnemyKindConfig {
+    fn default() -> EnemyKindConfig {
+        EnemyKindConfig {
+            speed: (ENEMY_SPEED, ENEMY_SPEED),
+            aimed_chance: 0.0,
+            aim_spread: 0.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct StarsConfig {
+    // Spawning over time pauses while this many stars are already out.
+    pub max_on_field: usize,
+    // Seconds a star stays before despawning, the last `fade_seconds` of which
+    // it spends fading out.
+    pub lifetime: f32,
+    pub fade_seconds: f32,
+    // A fresh normal star is worth `max_value`, dropping linearly to `min_value`
+    // by the end of its lifetime. Variants multiply this or set their own value.
+    pub max_value: u32,
+    pub min_value: u32,
+    pub drift_speed: f32,
+    // How close a player gets before a teleporting star hops away, and how far
+    // it goes at most. It then sits still for `teleport_cooldown` seconds.
+    pub teleport_radius: f32,
+    pub teleport_distance: f32,
+    pub teleport_cooldown: f32,
+    pub normal: StarVariantConfig,
+    pub gold: StarVariantConfig,
+    pub drifting: StarVariantConfig,
+    pub teleporting: StarVariantConfig,
+    pub cluster: StarClusterConfig,
+}
+
+impl Default for StarsConfig {
+    fn default() -> StarsConfig {
+        StarsConfig {
+            max_on_field: 15,
+            lifetime: 8.0,
+            fade_seconds: 1.5,
+            max_value: 3,
+            min_value: 1,
+            drift_speed: 60.0,
+            teleport_radius: 60.0,
+            teleport_distance: 200.0,
+            teleport_cooldown: 1.0,
+            normal: StarVariantConfig {
+                weight: 60,
+                ..default()
+            },
+            gold: StarVariantConfig {
+                weight: 5,
+                value: Some(5),
+                tint: (1.0, 0.8, 0.1, 1.0),
+                ..default()
+            },
+            drifting: StarVariantConfig {
+                weight: 15,
+                value_multiplier: 2,
+                tint: (0.5, 0.9, 1.0, 1.0),
+                ..default()
+            },
+            teleporting: StarVariantConfig {
+                weight: 10,
+                value_multiplier: 3,
+                tint: (0.9, 0.5, 1.0, 1.0),
+                ..default()
+            },
+            cluster: StarClusterConfig::default(),
+        }
+    }
+}
+
+impl StarsConfig {
+    pub fn variant(&self, kind: StarKind) -> &StarVariantConfig {
+        match kind {
+            StarKind::Normal => &self.normal,
+            StarKind::Gold => &self.gold,
+            StarKind::Drifting => &self.drifting,
+            StarKind::Teleporting => &self.teleporting,
+        }
+    }
+
+    pub fn value_at(&self, age: f32) -> u32 {
+        let remaining = 1.0 - (age / self.lifetime).clamp(0.0, 1.0);
+        let range = self.max_value.saturating_sub(self.min_value) as f32;
+        self.min_value + (range * remaining).ceil() as u32
+    }
+
+    // Sprite alpha for a star of this age; 1.0 until the fade starts.
+    pub fn alpha_at(&self, age: f32) -> f32 {
+        let fade_start = self.lifetime - self.fade_seconds;
+        if age <= fade_start || self.fade_seconds <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - (age - fade_start) / self.fade_seconds).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct StarVariantConfig {
+    // Relative chance of this variant in the spawn table; 0 disables it.
+    pub weight: u32,
+    // Multiplies the age-decayed value, unless a fixed `value` is set.
+    pub value_multiplier: u32,
+    pub value: Option<u32>,
+    pub sprite: String,
+    pub tint: (f32, f32, f32, f32),
+}
+
+impl Default for StarVariantConfig {
+    fn default() -> StarVariantConfig {
+        StarVariantConfig {
+            weight: 0,
+            value_multiplier: 1,
+            value: None,
+            sprite: "sprites/star.png".to_string(),
+            tint: (1.0, 1.0, 1.0, 1.0),
+        }
+    }
+}
+
+// A burst of normal stars around one point, rolled from the same table.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct StarClusterConfig {
+    pub weight: u32,
+    pub count: usize,
+    pub radius: f32,
+}
+
+impl Default for StarClusterConfig {
+    fn default() -> StarClusterConfig {
+        StarClusterConfig {
+            weight: 10,
+            count: 4,
+            radius: 60.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::star::components::Star;
+
+    #[test]
+    fn star_value_decays_from_max_to_min() {
+        let stars = StarsConfig::default();
+
+        assert_eq!(stars.value_at(0.0), stars.max_value);
+        assert_eq!(stars.value_at(stars.lifetime), stars.min_value);
+        assert_eq!(stars.value_at(stars.lifetime * 2.0), stars.min_value);
+        assert_eq!(stars.value_at(-1.0), stars.max_value);
+
+        let values: Vec<u32> = (0..=16)
+            .map(|step| stars.value_at(stars.lifetime * step as f32 / 16.0))
+            .collect();
+        assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn music_follows_the_app_state() {
+        let music = MusicConfig {
+            menu_track: Some("menu.wav".to_string()),
+            game_track: Some("game.wav".to_string()),
+            ..default()
+        };
+
+        assert_eq!(music.track_for(AppState::Game).unwrap(), "game.wav");
+        for app_state in [AppState::MainMenu, AppState::GameOver, AppState::Settings] {
+            assert_eq!(music.track_for(app_state).unwrap(), "menu.wav");
+        }
+
+        let silent_game = MusicConfig {
+            game_track: None,
+            ..music
+        };
+        assert_eq!(silent_game.track_for(AppState::Game), None);
+    }
+
+    #[test]
+    fn shipped_music_tracks_exist() {
+        let music = GameConfig::load().music;
+
+        for track in [&music.menu_track, &music.game_track] {
+            let track = track.as_ref().expect("the game ships with music");
+            assert!(asset_path(track).exists(), "{}", track);
+        }
+    }
+
+    #[test]
+    fn gold_stars_keep_their_value() {
+        let stars = StarsConfig::default();
+        let gold = |age| Star {
+            kind: StarKind::Gold,
+            age,
+        };
+
+        assert_eq!(gold(0.0).value(&stars), 5);
+        assert_eq!(gold(stars.lifetime).value(&stars), 5);
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct EditorUi {}
+#[derive(Component)]
+pub struct EditorStatusText {}
+
+// Anything drawn to show the level being edited; rebuilt after every change.
+#[derive(Component)]
+pub struct EditorItem {}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::systems::{
+    interactions::{
+        adjust_hovered_item, edit_with_mouse, open_editor, restore_current_level,
+        return_after_game_over, return_to_editor, save_level, select_tool, start_test_play,
+    },
+    layout::{despawn_editor, draw_editor_level, spawn_editor_ui, update_editor_text},
+};
+use resources::{EditorLevel, EditorSession};
+
+mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+pub const DEFAULT_LEVEL_PATH: &str = "levels/custom.level.ron"; // Where the editor saves.
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorLevel>()
+            .init_resource::<EditorSession>()
+            .add_systems(
+                (open_editor, spawn_editor_ui)
+                    .chain()
+                    .in_schedule(OnEnter(AppState::Editor)),
+            )
+            .add_systems(
+                (
+                    select_tool,
+                    edit_with_mouse,
+                    adjust_hovered_item,
+                    save_level,
+                    start_test_play,
+                    draw_editor_level,
+                    update_editor_text,
+                )
+                    .chain()
+                    .in_set(OnUpdate(AppState::Editor)),
+            )
+            .add_systems(
+                (despawn_editor, restore_current_level).in_schedule(OnExit(AppState::Editor)),
+            )
+            // Test-play runs the real game; these bring the designer back.
+            .add_system(return_to_editor.in_set(OnUpdate(AppState::Game)))
+            .add_system(return_after_game_over.in_schedule(OnEnter(AppState::GameOver)));
+    }
+}
@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use crate::game::arena::components::ObstacleKind;
+use crate::game::enemy::ENEMY_SIZE;
+use crate::game::level::loader::{EnemyPlacement, Level, ObstacleLayout, StarRegion};
+use crate::game::player::PLAYER_SIZE;
+
+pub const DEFAULT_REGION_SIZE: (f32, f32) = (240.0, 160.0);
+
+// The level being built and the file under `assets/` it saves to.
+#[derive(Resource, Default)]
+pub struct EditorLevel {
+    pub level: Level,
+    pub path: String,
+}
+
+#[derive(Resource, Default)]
+pub struct EditorSession {
+    pub tool: EditorTool,
+    pub dragging: Option<EditorTarget>,
+    // Set while the level is being played from the editor.
+    pub test_playing: bool,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EditorTool {
+    #[default]
+    Wall,
+    Pillar,
+    Bumper,
+    Enemy,
+    StarRegion,
+    PlayerStart,
+}
+
+impl EditorTool {
+    pub const ALL: [EditorTool; 6] = [
+        EditorTool::Wall,
+        EditorTool::Pillar,
+        EditorTool::Bumper,
+        EditorTool::Enemy,
+        EditorTool::StarRegion,
+        EditorTool::PlayerStart,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EditorTool::Wall => "Wall",
+            EditorTool::Pillar => "Pillar",
+            EditorTool::Bumper => "Bumper",
+            EditorTool::Enemy => "Enemy",
+            EditorTool::StarRegion => "Star region",
+            EditorTool::PlayerStart => "Player start",
+        }
+    }
+}
+
+// Something in the level that the mouse can grab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorTarget {
+    Obstacle(usize),
+    Enemy(usize),
+    StarRegion(usize),
+    PlayerStart,
+}
+
+// All positions here are in level coordinates.
+impl EditorLevel {
+    // Balls are picked before obstacles, and star regions last since they are
+    // the biggest and sit underneath everything.
+    pub fn target_at(&self, point: Vec2) -> Option<EditorTarget> {
+        let level = &self.level;
+
+        if let Some(player_start) = level.player_start {
+            if point.distance(to_vec(player_start)) < PLAYER_SIZE / 2.0 {
+                return Some(EditorTarget::PlayerStart);
+            }
+        }
+        let enemy = level.enemies.iter().rposition(|enemy| {
+            enemy
+                .position
+                .is_some_and(|position| point.distance(to_vec(position)) < ENEMY_SIZE / 2.0)
+        });
+        if let Some(index) = enemy {
+            return Some(EditorTarget::Enemy(index));
+        }
+        let obstacle = level.obstacles.iter().rposition(|obstacle| {
+            obstacle
+                .kind
+                .contact(to_vec(obstacle.position), point, 0.0)
+                .is_some()
+        });
+        if let Some(index) = obstacle {
+            return Some(EditorTarget::Obstacle(index));
+        }
+        level
+            .star_regions
+            .iter()
+            .rposition(|region| {
+                point.cmpge(to_vec(region.min)).all() && point.cmple(to_vec(region.max)).all()
+            })
+            .map(EditorTarget::StarRegion)
+    }
+
+    pub fn place(&mut self, tool: EditorTool, point: Vec2) {
+        let position = (point.x, point.y);
+        let level = &mut self.level;
+
+        match tool {
+            EditorTool::Wall => level.obstacles.push(ObstacleLayout {
+                kind: ObstacleKind::Wall {
+                    size: (200.0, 24.0),
+                },
+                position,
+            }),
+            EditorTool::Pillar => level.obstacles.push(ObstacleLayout {
+                kind: ObstacleKind::Pillar { radius: 40.0 },
+                position,
+            }),
+            EditorTool::Bumper => level.obstacles.push(ObstacleLayout {
+                kind: ObstacleKind::Bumper {
+                    radius: 28.0,
+                    kick: 400.0,
+                },
+                position,
+            }),
+            EditorTool::Enemy => level.enemies.push(EnemyPlacement {
+                position: Some(position),
+                direction: Some((1.0, 0.0)),
+                ..default()
+            }),
+            EditorTool::StarRegion => {
+                let half_size = to_vec(DEFAULT_REGION_SIZE) / 2.0;
+                level.star_regions.push(StarRegion {
+                    min: (point.x - half_size.x, point.y - half_size.y),
+                    max: (point.x + half_size.x, point.y + half_size.y),
+                });
+                let index = level.star_regions.len() - 1;
+                self.move_target(EditorTarget::StarRegion(index), point);
+            }
+            EditorTool::PlayerStart => level.player_start = Some(position),
+        }
+    }
+
+    pub fn move_target(&mut self, target: EditorTarget, point: Vec2) {
+        let arena_size = to_vec(self.level.arena_size);
+        let level = &mut self.level;
+
+        match target {
+            EditorTarget::Obstacle(index) => level.obstacles[index].position = (point.x, point.y),
+            EditorTarget::Enemy(index) => level.enemies[index].position = Some((point.x, point.y)),
+            EditorTarget::PlayerStart => level.player_start = Some((point.x, point.y)),
+            EditorTarget::StarRegion(index) => {
+                // Regions keep their size and stay inside the arena.
+                let region = &mut level.star_regions[index];
+                let half_size = (to_vec(region.max) - to_vec(region.min)) / 2.0;
+                let centre = point.clamp(half_size, (arena_size - half_size).max(half_size));
+                region.min = ((centre - half_size).x, (centre - half_size).y);
+                region.max = ((centre + half_size).x, (centre + half_size).y);
+            }
+        }
+    }
+
+    pub fn remove_target(&mut self, target: EditorTarget) {
+        let level = &mut self.level;
+
+        match target {
+            EditorTarget::Obstacle(index) => {
+                level.obstacles.remove(index);
+            }
+            EditorTarget::Enemy(index) => {
+                level.enemies.remove(index);
+            }
+            EditorTarget::StarRegion(index) => {
+                level.star_regions.remove(index);
+            }
+            EditorTarget::PlayerStart => level.player_start = None,
+        }
+    }
+
+    // Turns enemies by 45 degrees and walls by 90.
+    pub fn rotate_target(&mut self, target: EditorTarget) {
+        match target {
+            EditorTarget::Enemy(index) => {
+                let enemy = &mut self.level.enemies[index];
+                let direction = enemy.direction.map_or(Vec2::X, to_vec);
+                let rotated = Vec2::from_angle(std::f32::consts::FRAC_PI_4).rotate(direction);
+                enemy.direction = Some((rotated.x, rotated.y));
+            }
+            EditorTarget::Obstacle(index) => {
+                let obstacle = &mut self.level.obstacles[index];
+                if let ObstacleKind::Wall { size } = &mut obstacle.kind {
+                    *size = (size.1, size.0);
+                }
+            }
+            EditorTarget::StarRegion(_) | EditorTarget::PlayerStart => {}
+        }
+    }
+
+    pub fn resize_target(&mut self, target: EditorTarget, factor: f32) {
+        match target {
+            EditorTarget::Obstacle(index) => {
+                let obstacle = &mut self.level.obstacles[index];
+                match &mut obstacle.kind {
+                    ObstacleKind::Wall { size } => *size = (size.0 * factor, size.1 * factor),
+                    ObstacleKind::Pillar { radius } | ObstacleKind::Bumper { radius, .. } => {
+                        *radius *= factor
+                    }
+                }
+            }
+            EditorTarget::StarRegion(index) => {
+                let region = &mut self.level.star_regions[index];
+                let centre = (to_vec(region.min) + to_vec(region.max)) / 2.0;
+                let half_size = (to_vec(region.max) - to_vec(region.min)) / 2.0 * factor;
+                region.min = ((centre - half_size).x, (centre - half_size).y);
+                region.max = ((centre + half_size).x, (centre + half_size).y);
+                self.move_target(target, centre);
+            }
+            EditorTarget::Enemy(_) | EditorTarget::PlayerStart => {}
+        }
+    }
+}
+
+pub fn to_vec((x, y): (f32, f32)) -> Vec2 {
+    Vec2::new(x, y)
+}
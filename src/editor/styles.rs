@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+pub const STAR_REGION_COLOUR: Color = Color::rgba(1.0, 0.85, 0.2, 0.15);
+pub const DIRECTION_MARKER_COLOUR: Color = Color::WHITE;
+pub const PLAYER_START_COLOUR: Color = Color::rgba(1.0, 1.0, 1.0, 0.6);
+pub const HELP_TEXT_COLOUR: Color = Color::rgb(0.7, 0.7, 0.7);
+
+pub const EDITOR_UI_STYLE: Style = Style {
+    position_type: PositionType::Absolute,
+    position: UiRect {
+        left: Val::Px(12.),
+        top: Val::Px(8.),
+        ..UiRect::DEFAULT
+    },
+    flex_direction: FlexDirection::Column,
+    gap: Size::new(Val::Px(4.), Val::Px(4.)),
+    ..Style::DEFAULT
+};
+
+pub fn get_editor_text_style(game_assets: &GameAssets, colour: Color) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 18.,
+        color: colour,
+    }
+}
@@ -0,0 +1,248 @@
+use std::fs;
+
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use ron::ser::PrettyConfig;
+
+use crate::{
+    config::{asset_path, GameConfig},
+    editor::{
+        resources::{to_vec, EditorLevel, EditorSession, EditorTool},
+        DEFAULT_LEVEL_PATH,
+    },
+    game::{
+        level::{loader::Level, resources::CurrentLevel},
+        mode::resources::GameMode,
+    },
+    AppState,
+};
+
+const TOOL_KEYS: [KeyCode; 6] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+];
+const RESIZE_STEP: f32 = 1.1;
+
+// Window size over the level's arena size.
+pub fn level_scale(window: &Window, level: &Level) -> Vec2 {
+    Vec2::new(window.width(), window.height()) / to_vec(level.arena_size)
+}
+
+// The camera frames the window exactly, so the cursor position is also the
+// world position.
+fn cursor_in_level(window: &Window, level: &Level) -> Option<Vec2> {
+    let cursor = window.cursor_position()? / level_scale(window, level);
+    Some(cursor.clamp(Vec2::ZERO, to_vec(level.arena_size)))
+}
+
+pub fn open_editor(
+    mut editor_level: ResMut<EditorLevel>,
+    mut editor_session: ResMut<EditorSession>,
+    game_config: Res<GameConfig>,
+) {
+    // Coming back from a test-play keeps the level as it was.
+    if editor_session.test_playing {
+        editor_session.test_playing = false;
+        editor_session.status = "Back from test-play".to_string();
+        editor_level.set_changed();
+        return;
+    }
+
+    // Saves always go to the designer's own file so shipped levels are never
+    // overwritten. Until it exists, start from a copy of the configured level.
+    let path = DEFAULT_LEVEL_PATH.to_string();
+    let template = game_config.level.as_deref();
+    let (level, status) = if let Some(level) = read_level(&path) {
+        (level, format!("Editing {}", path))
+    } else if let Some(level) = template.and_then(read_level) {
+        let template = template.unwrap_or_default();
+        (level, format!("Editing a copy of {}", template))
+    } else {
+        (Level::default(), format!("New level, saves to {}", path))
+    };
+
+    *editor_level = EditorLevel { level, path };
+    *editor_session = EditorSession {
+        status,
+        ..default()
+    };
+}
+
+// Straight from disk rather than from `CurrentLevel`, which may still hold the
+// last level played.
+fn read_level(path: &str) -> Option<Level> {
+    let contents = fs::read_to_string(asset_path(path)).ok()?;
+    ron::from_str(&contents)
+        .map_err(|error| println!("Could not read level {}: {}", path, error))
+        .ok()
+}
+
+pub fn restore_current_level(
+    editor_session: Res<EditorSession>,
+    mut current_level: ResMut<CurrentLevel>,
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+) {
+    if !editor_session.test_playing {
+        *current_level = CurrentLevel::from_config(&asset_server, &game_config);
+    }
+}
+
+pub fn select_tool(keyboard_input: Res<Input<KeyCode>>, mut editor_session: ResMut<EditorSession>) {
+    for (key, tool) in TOOL_KEYS.iter().zip(EditorTool::ALL) {
+        if keyboard_input.just_pressed(*key) {
+            editor_session.tool = tool;
+        }
+    }
+}
+
+pub fn edit_with_mouse(
+    mouse_input: Res<Input<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut editor_level: ResMut<EditorLevel>,
+    mut editor_session: ResMut<EditorSession>,
+) {
+    if mouse_input.just_released(MouseButton::Left) {
+        editor_session.dragging = None;
+    }
+
+    let window = window_query.get_single().unwrap();
+    let Some(cursor) = cursor_in_level(window, &editor_level.level) else {
+        return;
+    };
+
+    if let Some(target) = editor_session.dragging {
+        if mouse_input.pressed(MouseButton::Left) {
+            editor_level.move_target(target, cursor);
+        }
+    } else if mouse_input.just_pressed(MouseButton::Left) {
+        match editor_level.target_at(cursor) {
+            Some(target) => editor_session.dragging = Some(target),
+            None => editor_level.place(editor_session.tool, cursor),
+        }
+    } else if mouse_input.just_pressed(MouseButton::Right) {
+        if let Some(target) = editor_level.target_at(cursor) {
+            editor_level.remove_target(target);
+        }
+    }
+}
+
+pub fn adjust_hovered_item(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut mouse_wheel_event_reader: EventReader<MouseWheel>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut editor_level: ResMut<EditorLevel>,
+) {
+    let scroll: f32 = mouse_wheel_event_reader.iter().map(|event| event.y).sum();
+    let rotate = keyboard_input.just_pressed(KeyCode::R);
+    if scroll == 0.0 && !rotate {
+        return;
+    }
+
+    let window = window_query.get_single().unwrap();
+    let Some(target) = cursor_in_level(window, &editor_level.level)
+        .and_then(|cursor| editor_level.target_at(cursor))
+    else {
+        return;
+    };
+
+    if rotate {
+        editor_level.rotate_target(target);
+    }
+    if scroll != 0.0 {
+        editor_level.resize_target(target, RESIZE_STEP.powf(scroll.signum()));
+    }
+}
+
+pub fn save_level(
+    keyboard_input: Res<Input<KeyCode>>,
+    editor_level: Res<EditorLevel>,
+    mut editor_session: ResMut<EditorSession>,
+    asset_server: Res<AssetServer>,
+) {
+    let control = keyboard_input.any_pressed([KeyCode::LControl, KeyCode::RControl]);
+    if !(control && keyboard_input.just_pressed(KeyCode::S)) {
+        return;
+    }
+    if !check_level(&editor_level.level, &mut editor_session) {
+        return;
+    }
+
+    let file_path = asset_path(&editor_level.path);
+    let result = ron::ser::to_string_pretty(&editor_level.level, PrettyConfig::default())
+        .map_err(|error| error.to_string())
+        .and_then(|contents| fs::write(&file_path, contents).map_err(|error| error.to_string()));
+
+    editor_session.status = match result {
+        Ok(()) => {
+            // Pick up the new file next time the level is played.
+            asset_server.reload_asset(editor_level.path.as_str());
+            format!("Saved to {}", file_path.display())
+        }
+        Err(error) => format!("Could not save: {}", error),
+    };
+}
+
+pub fn start_test_play(
+    keyboard_input: Res<Input<KeyCode>>,
+    editor_level: Res<EditorLevel>,
+    mut editor_session: ResMut<EditorSession>,
+    mut levels: ResMut<Assets<Level>>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut game_mode: ResMut<GameMode>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::T)
+        || !check_level(&editor_level.level, &mut editor_session)
+    {
+        return;
+    }
+
+    *current_level = CurrentLevel {
+        handle: Some(levels.add(editor_level.level.clone())),
+        objective: None,
+    };
+    // Test the level as written, without another mode's rules on top.
+    *game_mode = GameMode::Endless;
+    editor_session.test_playing = true;
+    app_state_next_state.set(AppState::Game);
+}
+
+// Shows the first problem in the status line; the rest are printed.
+fn check_level(level: &Level, editor_session: &mut EditorSession) -> bool {
+    let errors = level.validate();
+    for error in errors.iter().skip(1) {
+        println!("Level problem: {}", error);
+    }
+    match errors.first() {
+        Some(error) => {
+            editor_session.status = format!("Fix {} problem(s) first: {}", errors.len(), error);
+            false
+        }
+        None => true,
+    }
+}
+
+pub fn return_to_editor(
+    keyboard_input: Res<Input<KeyCode>>,
+    editor_session: Res<EditorSession>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if editor_session.test_playing && keyboard_input.just_pressed(KeyCode::Tab) {
+        app_state_next_state.set(AppState::Editor);
+    }
+}
+
+pub fn return_after_game_over(
+    editor_session: Res<EditorSession>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if editor_session.test_playing {
+        app_state_next_state.set(AppState::Editor);
+    }
+}
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::{
+    assets::GameAssets,
+    editor::{
+        components::{EditorItem, EditorStatusText, EditorUi},
+        resources::{to_vec, EditorLevel, EditorSession, EditorTool},
+        styles::{
+            get_editor_text_style, DIRECTION_MARKER_COLOUR, EDITOR_UI_STYLE, HELP_TEXT_COLOUR,
+            PLAYER_START_COLOUR, STAR_REGION_COLOUR,
+        },
+        systems::interactions::level_scale,
+    },
+    game::arena::spawn_obstacle,
+};
+
+const HELP_TEXT: &str = "Left click: place / drag   Right click: delete   Wheel: resize   \
+R: rotate   Ctrl+S: save   T: test-play (Tab returns)   M: main menu";
+
+pub fn spawn_editor_ui(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    editor_session: Res<EditorSession>,
+    editor_level: Res<EditorLevel>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: EDITOR_UI_STYLE,
+                ..default()
+            },
+            EditorUi {},
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                HELP_TEXT,
+                get_editor_text_style(&game_assets, HELP_TEXT_COLOUR),
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    get_status_label(&editor_session, &editor_level),
+                    get_editor_text_style(&game_assets, Color::WHITE),
+                ),
+                EditorStatusText {},
+            ));
+        });
+}
+
+pub fn despawn_editor(
+    mut commands: Commands,
+    editor_ui_query: Query<Entity, With<EditorUi>>,
+    editor_item_query: Query<Entity, With<EditorItem>>,
+) {
+    if let Ok(editor_ui_entity) = editor_ui_query.get_single() {
+        commands.entity(editor_ui_entity).despawn_recursive();
+    }
+    for editor_item_entity in editor_item_query.iter() {
+        commands.entity(editor_item_entity).despawn();
+    }
+}
+
+pub fn update_editor_text(
+    editor_session: Res<EditorSession>,
+    editor_level: Res<EditorLevel>,
+    mut text_query: Query<&mut Text, With<EditorStatusText>>,
+) {
+    if editor_session.is_changed() || editor_level.is_changed() {
+        if let Ok(mut text) = text_query.get_single_mut() {
+            text.sections[0].value = get_status_label(&editor_session, &editor_level);
+        }
+    }
+}
+
+fn get_status_label(editor_session: &EditorSession, editor_level: &EditorLevel) -> String {
+    let tools = EditorTool::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, tool)| {
+            if *tool == editor_session.tool {
+                format!("[{}: {}]", index + 1, tool.name())
+            } else {
+                format!("{}: {}", index + 1, tool.name())
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("  ");
+
+    format!(
+        "{}   |   {}   {}",
+        editor_level.path, tools, editor_session.status
+    )
+}
+
+// Rebuilds every marker whenever the level changes; levels are small enough
+// that this is simpler than patching individual entities.
+pub fn draw_editor_level(
+    mut commands: Commands,
+    editor_level: Res<EditorLevel>,
+    editor_item_query: Query<Entity, With<EditorItem>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    game_assets: Res<GameAssets>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !editor_level.is_changed() {
+        return;
+    }
+    for editor_item_entity in editor_item_query.iter() {
+        commands.entity(editor_item_entity).despawn();
+    }
+
+    let window = window_query.get_single().unwrap();
+    let level = &editor_level.level;
+    let scale = level_scale(window, level);
+
+    for region in level.star_regions.iter() {
+        let min = to_vec(region.min) * scale;
+        let max = to_vec(region.max) * scale;
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_translation(((min + max) / 2.0).extend(-0.6)),
+                sprite: Sprite {
+                    color: STAR_REGION_COLOUR,
+                    custom_size: Some(max - min),
+                    ..default()
+                },
+                ..default()
+            },
+            EditorItem {},
+        ));
+    }
+
+    for obstacle in level.obstacles.iter() {
+        let obstacle_entity = spawn_obstacle(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            obstacle.kind.scaled(scale),
+            to_vec(obstacle.position) * scale,
+        );
+        commands.entity(obstacle_entity).insert(EditorItem {});
+    }
+
+    for enemy in level.enemies.iter() {
+        // Enemies without a position appear somewhere random in play.
+        let Some(position) = enemy.position else {
+            continue;
+        };
+        let position = to_vec(position) * scale;
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_translation(position.extend(0.0)),
+                texture: game_assets.enemy(enemy.kind),
+                ..default()
+            },
+            EditorItem {},
+        ));
+        if let Some(direction) = enemy.direction {
+            let marker = position + to_vec(direction).normalize_or_zero() * 44.0;
+            commands.spawn((
+                SpriteBundle {
+                    transform: Transform::from_translation(marker.extend(0.1)),
+                    sprite: Sprite {
+                        color: DIRECTION_MARKER_COLOUR,
+                        custom_size: Some(Vec2::splat(10.0)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                EditorItem {},
+            ));
+        }
+    }
+
+    if let Some(player_start) = level.player_start {
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_translation((to_vec(player_start) * scale).extend(0.0)),
+                texture: game_assets.player.clone(),
+                sprite: Sprite {
+                    color: PLAYER_START_COLOUR,
+                    ..default()
+                },
+                ..default()
+            },
+            EditorItem {},
+        ));
+    }
+}
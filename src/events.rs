@@ -1,3 +1,72 @@
+use bevy::prelude::*;
+
+use crate::game::enemy::components::EnemyKind;
+use crate::game::spawner::components::{Placement, SpawnKind};
+
+// Gameplay events. The core systems only send these; audio, score and anything
+// else that cares about what happened reads them.
+
 pub struct GameOver {
     pub score: u32,
 }
+
+pub struct EnemySpawned {
+    pub entity: Entity,
+    pub position: Vec2,
+}
+
+// Asks the spawner for a new entity instead of spawning it directly, so every
+// kind goes through the same placement rules.
+pub struct SpawnRequest {
+    pub kind: SpawnKind,
+    pub placement: Placement,
+}
+
+// A ball came off one of the arena walls.
+pub struct EnemyBounced {
+    pub position: Vec2,
+    pub normal: Vec2,
+}
+
+// `player_id` is who picked it up, as in `PlayerHit`.
+pub struct StarCollected {
+    pub player_id: u32,
+    pub position: Vec2,
+    pub value: u32,
+}
+
+// `player_id` is `LOCAL_PLAYER_ID` for this machine's player, otherwise the
+// networked player's id.
+pub struct PlayerHit {
+    pub player_id: u32,
+    pub enemy: Entity,
+    pub enemy_kind: EnemyKind,
+    pub position: Vec2,
+}
+
+// Sent every `COMBO_MILESTONE` stars collected in quick succession.
+pub struct ComboReached {
+    pub combo: u32,
+}
+
+// An enemy came within `NEAR_MISS_MARGIN` of the player and left again.
+pub struct NearMiss {
+    pub enemy: Entity,
+}
+
+pub struct WaveStarted {
+    pub wave: u32,
+}
+
+// The level asset is ready and the arena, enemies and stars can be set up.
+pub struct LevelStarted {}
+
+// The level's win condition was met. A `GameOver` follows in the same frame.
+pub struct LevelCompleted {
+    pub score: u32,
+}
+
+pub struct AchievementUnlocked {
+    pub name: String,
+    pub description: String,
+}
@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::collision::time_of_impact;
+
+#[derive(Component)]
+pub struct Obstacle {
+    pub kind: ObstacleKind,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum ObstacleKind {
+    // An axis-aligned block.
+    Wall { size: (f32, f32) },
+    Pillar { radius: f32 },
+    // Like a pillar, but kicks the player away with `kick` extra speed.
+    Bumper { radius: f32, kick: f32 },
+}
+
+impl ObstacleKind {
+    // Level files are laid out for their own arena size; stretch to the window.
+    pub fn scaled(&self, scale: Vec2) -> ObstacleKind {
+        let radius_scale = scale.min_element();
+        match *self {
+            ObstacleKind::Wall { size } => ObstacleKind::Wall {
+                size: (size.0 * scale.x, size.1 * scale.y),
+            },
+            ObstacleKind::Pillar { radius } => ObstacleKind::Pillar {
+                radius: radius * radius_scale,
+            },
+            ObstacleKind::Bumper { radius, kick } => ObstacleKind::Bumper {
+                radius: radius * radius_scale,
+                kick,
+            },
+        }
+    }
+
+    // Outward normal and overlap depth when a ball of `radius` at `position`
+    // overlaps this obstacle centred on `centre`.
+    pub fn contact(&self, centre: Vec2, position: Vec2, radius: f32) -> Option<(Vec2, f32)> {
+        match *self {
+            ObstacleKind::Wall { size } => {
+                let half_size = Vec2::new(size.0, size.1) / 2.0;
+                let closest = position.clamp(centre - half_size, centre + half_size);
+                let offset = position - closest;
+
+                if offset == Vec2::ZERO {
+                    // The centre is inside the block; leave by the nearest face.
+                    let inside = position - centre;
+                    let depth = half_size - inside.abs();
+                    return Some(if depth.x < depth.y {
+                        (Vec2::new(inside.x.signum(), 0.0), depth.x + radius)
+                    } else {
+                        (Vec2::new(0.0, inside.y.signum()), depth.y + radius)
+                    });
+                }
+
+                let distance = offset.length();
+                (distance < radius).then(|| (offset / distance, radius - distance))
+            }
+            ObstacleKind::Pillar {
+                radius: obstacle_radius,
+            }
+            | ObstacleKind::Bumper {
+                radius: obstacle_radius,
+                ..
+            } => {
+                let offset = position - centre;
+                let distance = offset.length();
+                if distance >= radius + obstacle_radius {
+                    return None;
+                }
+                let normal = if distance > 0.0 {
+                    offset / distance
+                } else {
+                    Vec2::Y
+                };
+                Some((normal, radius + obstacle_radius - distance))
+            }
+        }
+    }
+
+    // The fraction of the frame, from 0.0 to 1.0, at which a ball of `radius`
+    // moving from `start` by `motion` first touches this obstacle, so fast
+    // balls can't pass through thin walls between frames.
+    pub fn time_of_impact(
+        &self,
+        centre: Vec2,
+        start: Vec2,
+        motion: Vec2,
+        radius: f32,
+    ) -> Option<f32> {
+        match *self {
+            ObstacleKind::Wall { size } => {
+                if self.contact(centre, start, radius).is_some() {
+                    return Some(0.0);
+                }
+
+                // Sweep the centre against the block grown by the radius.
+                let half_size = Vec2::new(size.0, size.1) / 2.0;
+                let grown = half_size + radius;
+                let offset = start - centre;
+                let mut enter: f32 = 0.0;
+                let mut exit: f32 = 1.0;
+                for (offset, motion, grown) in
+                    [(offset.x, motion.x, grown.x), (offset.y, motion.y, grown.y)]
+                {
+                    if motion == 0.0 {
+                        if offset.abs() > grown {
+                            return None;
+                        }
+                        continue;
+                    }
+                    let near = (-grown - offset) / motion;
+                    let far = (grown - offset) / motion;
+                    enter = enter.max(near.min(far));
+                    exit = exit.min(near.max(far));
+                    if enter > exit {
+                        return None;
+                    }
+                }
+
+                // Past both faces the grown block is rounded, so test the corner.
+                let hit = offset + motion * enter;
+                if hit.x.abs() > half_size.x && hit.y.abs() > half_size.y {
+                    let corner =
+                        Vec2::new(half_size.x.copysign(hit.x), half_size.y.copysign(hit.y));
+                    return time_of_impact(offset - corner, motion, radius);
+                }
+                Some(enter)
+            }
+            ObstacleKind::Pillar {
+                radius: obstacle_radius,
+            }
+            | ObstacleKind::Bumper {
+                radius: obstacle_radius,
+                ..
+            } => time_of_impact(start - centre, motion, radius + obstacle_radius),
+        }
+    }
+
+    // Outward normal for a ball at `position` touching this obstacle.
+    pub fn normal(&self, centre: Vec2, position: Vec2) -> Vec2 {
+        match *self {
+            ObstacleKind::Wall { size } => {
+                let half_size = Vec2::new(size.0, size.1) / 2.0;
+                let closest = position.clamp(centre - half_size, centre + half_size);
+                (position - closest).try_normalize().unwrap_or_else(|| {
+                    let inside = position - centre;
+                    let depth = half_size - inside.abs();
+                    if depth.x < depth.y {
+                        Vec2::new(inside.x.signum(), 0.0)
+                    } else {
+                        Vec2::new(0.0, inside.y.signum())
+                    }
+                })
+            }
+            ObstacleKind::Pillar { .. } | ObstacleKind::Bumper { .. } => {
+                (position - centre).try_normalize().unwrap_or(Vec2::Y)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALL: ObstacleKind = ObstacleKind::Wall {
+        size: (20.0, 200.0),
+    };
+    const PILLAR: ObstacleKind = ObstacleKind::Pillar { radius: 50.0 };
+
+    #[test]
+    fn ball_beside_a_wall_is_pushed_out_sideways() {
+        let (normal, depth) = WALL
+            .contact(Vec2::ZERO, Vec2::new(25.0, 0.0), 20.0)
+            .unwrap();
+        assert_eq!(normal, Vec2::X);
+        assert!((depth - 5.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn ball_centred_inside_a_wall_leaves_by_the_nearest_face() {
+        let (normal, depth) = WALL
+            .contact(Vec2::ZERO, Vec2::new(-5.0, 50.0), 20.0)
+            .unwrap();
+        assert_eq!(normal, Vec2::NEG_X);
+        assert!((depth - 25.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn ball_clear_of_obstacles_has_no_contact() {
+        assert!(WALL
+            .contact(Vec2::ZERO, Vec2::new(40.0, 0.0), 20.0)
+            .is_none());
+        assert!(PILLAR
+            .contact(Vec2::ZERO, Vec2::new(0.0, 80.0), 20.0)
+            .is_none());
+    }
+
+    #[test]
+    fn ball_overlapping_a_pillar_is_pushed_out_radially() {
+        let (normal, depth) = PILLAR
+            .contact(Vec2::ZERO, Vec2::new(0.0, -60.0), 20.0)
+            .unwrap();
+        assert_eq!(normal, Vec2::NEG_Y);
+        assert!((depth - 10.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn fast_ball_cannot_pass_through_a_thin_wall() {
+        // Starts and ends the frame clear of the wall on opposite sides.
+        let start = Vec2::new(-100.0, 0.0);
+        let motion = Vec2::new(200.0, 0.0);
+        assert!(WALL.contact(Vec2::ZERO, start + motion, 20.0).is_none());
+
+        let time = WALL
+            .time_of_impact(Vec2::ZERO, start, motion, 20.0)
+            .unwrap();
+        // Touches when its centre is 30 from the wall's centre.
+        assert!((time - 0.35).abs() < 1e-5);
+        assert_eq!(WALL.normal(Vec2::ZERO, start + motion * time), Vec2::NEG_X);
+    }
+
+    #[test]
+    fn sweep_rounds_the_corners_of_a_wall() {
+        // Cuts diagonally across the corner of the grown box but stays clear
+        // of the rounded corner itself.
+        let start = Vec2::new(-60.0, 82.0);
+        let motion = Vec2::new(60.0, 60.0);
+        assert!(WALL
+            .time_of_impact(Vec2::ZERO, start, motion, 20.0)
+            .is_none());
+
+        // Level with the corner it clips it.
+        let start = Vec2::new(-100.0, 110.0);
+        let motion = Vec2::new(200.0, 0.0);
+        let time = WALL
+            .time_of_impact(Vec2::ZERO, start, motion, 20.0)
+            .unwrap();
+        let contact = start + motion * time;
+        assert!((contact.distance(Vec2::new(-10.0, 100.0)) - 20.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn fast_ball_hits_a_pillar_mid_frame() {
+        let time = PILLAR
+            .time_of_impact(
+                Vec2::ZERO,
+                Vec2::new(0.0, -200.0),
+                Vec2::new(0.0, 400.0),
+                20.0,
+            )
+            .unwrap();
+        assert!((time - 0.325).abs() < 1e-5);
+    }
+
+    #[test]
+    fn sweep_ignores_balls_moving_away() {
+        let start = Vec2::new(40.0, 0.0);
+        let motion = Vec2::new(100.0, 0.0);
+        assert!(WALL
+            .time_of_impact(Vec2::ZERO, start, motion, 20.0)
+            .is_none());
+        assert!(PILLAR
+            .time_of_impact(Vec2::ZERO, Vec2::new(80.0, 0.0), motion, 20.0)
+            .is_none());
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use systems::*;
+
+pub use systems::spawn_obstacle;
+
+use crate::events::LevelStarted;
+use crate::AppState;
+
+use super::network::is_authority;
+use super::player::ConfinementSystemSet;
+use super::SimulationState;
+
+pub const OBSTACLE_Z: f32 = -0.5; // Drawn under the balls and stars.
+
+pub struct ArenaPlugin;
+
+impl Plugin for ArenaPlugin {
+    fn build(&self, app: &mut App) {
+        // Every peer builds the same arena from its own copy of the level, so
+        // obstacles are never sent over the network.
+        app.add_system(
+            spawn_obstacles
+                .in_set(OnUpdate(AppState::Game))
+                .run_if(on_event::<LevelStarted>()),
+        )
+        .add_systems(
+            (player_hit_obstacles, enemy_hit_obstacles)
+                .in_set(ConfinementSystemSet)
+                .in_set(OnUpdate(AppState::Game))
+                .in_set(OnUpdate(SimulationState::Running))
+                .distributive_run_if(is_authority),
+        )
+        .add_system(despawn_obstacles.in_schedule(OnExit(AppState::Game)));
+    }
+}
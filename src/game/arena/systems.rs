@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use super::components::{Obstacle, ObstacleKind};
+use super::OBSTACLE_Z;
+use crate::config::GameConfig;
+use crate::events::EnemyBounced;
+use crate::game::collision::components::PreviousPosition;
+use crate::game::collision::MAX_WALL_BOUNCES;
+use crate::game::enemy::components::Enemy;
+use crate::game::enemy::ENEMY_SIZE;
+use crate::game::level::resources::ActiveLevel;
+use crate::game::network::components::RemotePlayer;
+use crate::game::player::components::{Player, Velocity};
+use crate::game::player::PLAYER_SIZE;
+
+const WALL_COLOUR: Color = Color::rgb(0.3, 0.3, 0.35);
+const PILLAR_COLOUR: Color = Color::rgb(0.45, 0.45, 0.5);
+const BUMPER_COLOUR: Color = Color::rgb(0.9, 0.4, 0.7);
+
+type PlayerFilter = (Or<(With<Player>, With<RemotePlayer>)>, Without<Obstacle>);
+
+pub fn spawn_obstacles(
+    mut commands: Commands,
+    active_level: Res<ActiveLevel>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for obstacle in active_level.level.obstacles.iter() {
+        spawn_obstacle(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            obstacle.kind.scaled(active_level.scale),
+            active_level.to_world(obstacle.position),
+        );
+    }
+}
+
+// Also used by the editor to draw the level being built.
+pub fn spawn_obstacle(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    kind: ObstacleKind,
+    position: Vec2,
+) -> Entity {
+    let transform = Transform::from_translation(position.extend(OBSTACLE_Z));
+
+    match kind {
+        ObstacleKind::Wall { size } => commands
+            .spawn((
+                SpriteBundle {
+                    transform,
+                    sprite: Sprite {
+                        color: WALL_COLOUR,
+                        custom_size: Some(Vec2::new(size.0, size.1)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Obstacle { kind },
+            ))
+            .id(),
+        ObstacleKind::Pillar { radius } | ObstacleKind::Bumper { radius, .. } => {
+            let colour = match kind {
+                ObstacleKind::Bumper { .. } => BUMPER_COLOUR,
+                _ => PILLAR_COLOUR,
+            };
+            commands
+                .spawn((
+                    MaterialMesh2dBundle {
+                        transform,
+                        mesh: meshes.add(shape::Circle::new(radius).into()).into(),
+                        material: materials.add(ColorMaterial::from(colour)),
+                        ..default()
+                    },
+                    Obstacle { kind },
+                ))
+                .id()
+        }
+    }
+}
+
+pub fn despawn_obstacles(mut commands: Commands, obstacle_query: Query<Entity, With<Obstacle>>) {
+    for obstacle_entity in obstacle_query.iter() {
+        commands.entity(obstacle_entity).despawn();
+    }
+}
+
+pub fn player_hit_obstacles(
+    mut player_query: Query<(&mut Transform, &mut Velocity, &PreviousPosition), PlayerFilter>,
+    obstacle_query: Query<(&Transform, &Obstacle)>,
+    game_config: Res<GameConfig>,
+) {
+    let wall_bounciness = game_config.player.wall_bounciness;
+    for (mut player_transform, mut velocity, previous_position) in player_query.iter_mut() {
+        let radius = PLAYER_SIZE * player_transform.scale.x / 2.0;
+        let motion = player_transform.translation.truncate() - previous_position.value;
+
+        // Stop where the player first touched an obstacle this frame rather
+        // than letting a dash carry them through it.
+        if let Some((time, normal, kind)) =
+            first_impact(&obstacle_query, previous_position.value, motion, radius)
+        {
+            let position = previous_position.value + motion * time;
+            player_transform.translation = position.extend(player_transform.translation.z);
+            bounce_player(&mut velocity, normal, kind, wall_bounciness);
+            continue;
+        }
+
+        for (obstacle_transform, obstacle) in obstacle_query.iter() {
+            let Some((normal, depth)) = obstacle.kind.contact(
+                obstacle_transform.translation.truncate(),
+                player_transform.translation.truncate(),
+                radius,
+            ) else {
+                continue;
+            };
+
+            player_transform.translation += (normal * depth).extend(0.0);
+            bounce_player(&mut velocity, normal, obstacle.kind, wall_bounciness);
+        }
+    }
+}
+
+fn bounce_player(velocity: &mut Velocity, normal: Vec2, kind: ObstacleKind, wall_bounciness: f32) {
+    let approach_speed = velocity.value.dot(normal);
+    if approach_speed < 0.0 {
+        velocity.value -= normal * approach_speed * (1.0 + wall_bounciness);
+    }
+    if let ObstacleKind::Bumper { kick, .. } = kind {
+        velocity.value += normal * kick;
+    }
+}
+
+pub fn enemy_hit_obstacles(
+    mut enemy_query: Query<(&mut Transform, &mut Enemy, &PreviousPosition), Without<Obstacle>>,
+    obstacle_query: Query<(&Transform, &Obstacle)>,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>,
+) {
+    let radius = ENEMY_SIZE / 2.0;
+    for (mut enemy_transform, mut enemy, previous_position) in enemy_query.iter_mut() {
+        // Bounce at the exact point the ball reaches an obstacle, however far
+        // it travels this frame, so it can't skip over a thin wall.
+        let mut position = previous_position.value;
+        let mut motion = enemy_transform.translation.truncate() - position;
+        let mut bounced = false;
+        for _ in 0..MAX_WALL_BOUNCES {
+            let Some((time, normal, _)) = first_impact(&obstacle_query, position, motion, radius)
+            else {
+                break;
+            };
+
+            position += motion * time;
+            let approach = enemy.direction.dot(normal);
+            if approach < 0.0 {
+                enemy.direction -= 2.0 * approach * normal;
+            }
+            enemy_bounced_event_writer.send(EnemyBounced { position, normal });
+            motion = enemy.direction * motion.length() * (1.0 - time);
+            bounced = true;
+        }
+        if bounced {
+            position += motion;
+            enemy_transform.translation = position.extend(enemy_transform.translation.z);
+        }
+
+        // Balls that start the frame overlapping, e.g. from a spawn, are pushed out.
+        for (obstacle_transform, obstacle) in obstacle_query.iter() {
+            let Some((normal, depth)) = obstacle.kind.contact(
+                obstacle_transform.translation.truncate(),
+                enemy_transform.translation.truncate(),
+                radius,
+            ) else {
+                continue;
+            };
+
+            enemy_transform.translation += (normal * depth).extend(0.0);
+            // Enemies keep a constant speed, so bumpers only redirect them.
+            let approach = enemy.direction.dot(normal);
+            if approach < 0.0 {
+                enemy.direction -= 2.0 * approach * normal;
+                enemy_bounced_event_writer.send(EnemyBounced {
+                    position: enemy_transform.translation.truncate(),
+                    normal,
+                });
+            }
+        }
+    }
+}
+
+// The first obstacle a ball of `radius` reaches while moving from `start` by
+// `motion`: the fraction of the motion travelled and the normal where it
+// touches. Balls already overlapping are left to the push-out.
+fn first_impact(
+    obstacle_query: &Query<(&Transform, &Obstacle)>,
+    start: Vec2,
+    motion: Vec2,
+    radius: f32,
+) -> Option<(f32, Vec2, ObstacleKind)> {
+    let mut impact: Option<(f32, Vec2, ObstacleKind)> = None;
+    for (obstacle_transform, obstacle) in obstacle_query.iter() {
+        let centre = obstacle_transform.translation.truncate();
+        let Some(time) = obstacle.kind.time_of_impact(centre, start, motion, radius) else {
+            continue;
+        };
+        if time > 0.0 && impact.is_none_or(|(closest, _, _)| time < closest) {
+            let normal = obstacle.kind.normal(centre, start + motion * time);
+            impact = Some((time, normal, obstacle.kind));
+        }
+    }
+    impact
+}
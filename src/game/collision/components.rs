@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+// Where a ball was before this frame's movement, so hits can be checked along
+// the whole path it took rather than only where it ended up.
+#[derive(Component)]
+pub struct PreviousPosition {
+    pub value: Vec2,
+}
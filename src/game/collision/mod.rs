@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use systems::*;
+
+pub use systems::time_of_impact;
+
+use crate::AppState;
+
+use super::network::is_authority;
+use super::player::{ConfinementSystemSet, MovementSystemSet};
+use super::SimulationState;
+
+pub const MAX_WALL_BOUNCES: usize = 4; // Per enemy per frame; enough for a corner.
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (
+                record_previous_positions.before(MovementSystemSet),
+                enemies_collide.in_set(ConfinementSystemSet),
+            )
+                .in_set(OnUpdate(AppState::Game))
+                .in_set(OnUpdate(SimulationState::Running))
+                .distributive_run_if(is_authority),
+        );
+    }
+}
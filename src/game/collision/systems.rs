@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+
+use super::components::PreviousPosition;
+use crate::events::EnemyBounced;
+use crate::game::enemy::components::Enemy;
+use crate::game::enemy::ENEMY_SIZE;
+
+pub fn record_previous_positions(mut ball_query: Query<(&Transform, &mut PreviousPosition)>) {
+    for (transform, mut previous_position) in ball_query.iter_mut() {
+        previous_position.value = transform.translation.truncate();
+    }
+}
+
+// The fraction of the frame, from 0.0 to 1.0, at which two circles first touch.
+// `offset` is the second circle's start relative to the first and `motion` how
+// far it moved relative to the first; circles already touching hit at 0.0.
+pub fn time_of_impact(offset: Vec2, motion: Vec2, radius: f32) -> Option<f32> {
+    let c = offset.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let a = motion.length_squared();
+    let b = offset.dot(motion);
+    // Not closing in, or never getting within `radius`.
+    if a == 0.0 || b >= 0.0 {
+        return None;
+    }
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let time = (-b - discriminant.sqrt()) / a;
+    (time <= 1.0).then_some(time)
+}
+
+// Enemies bounce off each other like they do off pillars, keeping their speed.
+pub fn enemies_collide(
+    mut enemy_query: Query<(&mut Transform, &mut Enemy, &PreviousPosition)>,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>,
+) {
+    let mut combinations = enemy_query.iter_combinations_mut();
+    while let Some(
+        [(mut transform_a, mut enemy_a, previous_a), (mut transform_b, mut enemy_b, previous_b)],
+    ) = combinations.fetch_next()
+    {
+        let motion_a = transform_a.translation.truncate() - previous_a.value;
+        let motion_b = transform_b.translation.truncate() - previous_b.value;
+        let Some(time) = time_of_impact(
+            previous_b.value - previous_a.value,
+            motion_b - motion_a,
+            ENEMY_SIZE,
+        ) else {
+            continue;
+        };
+
+        let contact_a = previous_a.value + motion_a * time;
+        let contact_b = previous_b.value + motion_b * time;
+        let Some(normal) = (contact_b - contact_a).try_normalize() else {
+            continue;
+        };
+        // Already moving apart; a spawn overlap shouldn't lock them together.
+        if (motion_b - motion_a).dot(normal) >= 0.0 {
+            continue;
+        }
+
+        let approach_a = enemy_a.direction.dot(normal);
+        if approach_a > 0.0 {
+            enemy_a.direction -= 2.0 * approach_a * normal;
+        }
+        let approach_b = enemy_b.direction.dot(normal);
+        if approach_b < 0.0 {
+            enemy_b.direction -= 2.0 * approach_b * normal;
+        }
+        // Spend the rest of the frame's travel along the new headings.
+        let remaining = 1.0 - time;
+        let position_a = contact_a + enemy_a.direction * motion_a.length() * remaining;
+        let position_b = contact_b + enemy_b.direction * motion_b.length() * remaining;
+        transform_a.translation = position_a.extend(transform_a.translation.z);
+        transform_b.translation = position_b.extend(transform_b.translation.z);
+
+        enemy_bounced_event_writer.send(EnemyBounced {
+            position: (contact_a + contact_b) / 2.0,
+            normal,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_on_hit_is_found_mid_frame() {
+        // 100 apart, closing by 100 with a combined radius of 20: touch at 80.
+        let time = time_of_impact(Vec2::new(100.0, 0.0), Vec2::new(-100.0, 0.0), 20.0);
+        assert!((time.unwrap() - 0.8).abs() < 1e-5);
+    }
+
+    #[test]
+    fn fast_pass_through_is_not_missed() {
+        // Ends the frame well past the other circle, which a check of the end
+        // positions alone would miss.
+        let time = time_of_impact(Vec2::new(100.0, 0.0), Vec2::new(-300.0, 0.0), 10.0);
+        assert!((time.unwrap() - 0.3).abs() < 1e-5);
+    }
+
+    #[test]
+    fn already_touching_hits_at_once() {
+        assert_eq!(
+            time_of_impact(Vec2::new(5.0, 0.0), Vec2::ZERO, 10.0),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn misses_are_none() {
+        // Moving apart.
+        assert_eq!(
+            time_of_impact(Vec2::new(100.0, 0.0), Vec2::new(50.0, 0.0), 20.0),
+            None
+        );
+        // Standing still.
+        assert_eq!(
+            time_of_impact(Vec2::new(100.0, 0.0), Vec2::ZERO, 20.0),
+            None
+        );
+        // Passing by too far to the side.
+        assert_eq!(
+            time_of_impact(Vec2::new(100.0, 50.0), Vec2::new(-200.0, 0.0), 20.0),
+            None
+        );
+        // Heading in but not getting there this frame.
+        assert_eq!(
+            time_of_impact(Vec2::new(100.0, 0.0), Vec2::new(-50.0, 0.0), 20.0),
+            None
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+use crate::AppState;
+
+use super::mode::resources::GameMode;
+
+pub const MODIFIER_CHANCE: f64 = 0.5; // Chance of each modifier being in a given day's set.
+
+pub struct DailyChallengePlugin;
+
+impl Plugin for DailyChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DailyChallenge::today()).add_systems(
+            (
+                refresh_daily_challenge,
+                seed_game_rng,
+                apply_daily_modifiers.run_if(is_daily_challenge),
+            )
+                .chain()
+                .in_schedule(OnEnter(AppState::Game)),
+        );
+    }
+}
+
+pub fn is_daily_challenge(game_mode: Res<GameMode>) -> bool {
+    *game_mode == GameMode::DailyChallenge
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use chrono::Local;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use super::MODIFIER_CHANCE;
+use crate::game::modifiers::resources::Modifier;
+
+// Today's run. Everything is derived from the date, so everyone who plays on
+// the same day gets the same seed and modifiers. The day is the player's local
+// one, so a new challenge starts at their midnight.
+#[derive(Resource, Debug)]
+pub struct DailyChallenge {
+    // "YYYY-MM-DD", also the key of the day's high-score table.
+    pub date: String,
+    pub seed: u64,
+    pub modifiers: Vec<Modifier>,
+}
+
+impl DailyChallenge {
+    pub fn today() -> DailyChallenge {
+        DailyChallenge::for_date(Local::now().date_naive().format("%Y-%m-%d").to_string())
+    }
+
+    pub fn for_date(date: String) -> DailyChallenge {
+        let seed = scramble(date_number(&date));
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut modifiers: Vec<Modifier> = Modifier::DAILY
+            .into_iter()
+            .filter(|_| rng.gen_bool(MODIFIER_CHANCE))
+            .collect();
+        // A challenge without a twist is just an endless run.
+        if modifiers.is_empty() {
+            modifiers.extend(Modifier::DAILY.choose(&mut rng));
+        }
+
+        DailyChallenge {
+            date,
+            seed,
+            modifiers,
+        }
+    }
+
+    pub fn describe_modifiers(&self) -> String {
+        self.modifiers
+            .iter()
+            .map(Modifier::name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+// Spreads consecutive dates far apart (the splitmix64 finaliser).
+fn scramble(date: u64) -> u64 {
+    let mut z = date.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+// "2026-10-19" as 20261019, so the seed doesn't depend on a hasher that
+// might change between builds.
+fn date_number(date: &str) -> u64 {
+    date.bytes()
+        .filter(u8::is_ascii_digit)
+        .fold(0, |number, digit| number * 10 + u64::from(digit - b'0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scramble_spreads_consecutive_days() {
+        let seeds: Vec<u64> = (20_000..20_100).map(scramble).collect();
+
+        let mut unique = seeds.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), seeds.len());
+        assert!(seeds
+            .windows(2)
+            .all(|pair| (pair[0] ^ pair[1]).count_ones() > 8));
+    }
+
+    #[test]
+    fn same_day_gives_the_same_challenge() {
+        let first = DailyChallenge::for_date("2026-10-19".to_string());
+        let second = DailyChallenge::for_date("2026-10-19".to_string());
+
+        assert_eq!(first.date, second.date);
+        assert_eq!(first.seed, second.seed);
+        assert_eq!(first.modifiers, second.modifiers);
+        assert!(!first.modifiers.is_empty());
+    }
+
+    #[test]
+    fn next_day_gives_a_new_seed() {
+        let today = DailyChallenge::for_date("2026-10-19".to_string());
+        let tomorrow = DailyChallenge::for_date("2026-10-20".to_string());
+
+        assert_eq!(today.date, "2026-10-19");
+        assert_ne!(today.seed, tomorrow.seed);
+    }
+}
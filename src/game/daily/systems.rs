@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use super::resources::DailyChallenge;
+use crate::game::mode::resources::GameMode;
+use crate::game::modifiers::resources::RunModifiers;
+use crate::game::resources::GameRng;
+
+// The game may have been left open past midnight.
+pub fn refresh_daily_challenge(mut daily_challenge: ResMut<DailyChallenge>) {
+    let today = DailyChallenge::today();
+    if today.date != daily_challenge.date {
+        *daily_challenge = today;
+    }
+}
+
+// Every run gets a fresh seed except the daily challenge, which replays the
+// day's seed each attempt.
+pub fn seed_game_rng(
+    mut game_rng: ResMut<GameRng>,
+    game_mode: Res<GameMode>,
+    daily_challenge: Res<DailyChallenge>,
+) {
+    game_rng.rng = match *game_mode {
+        GameMode::DailyChallenge => StdRng::seed_from_u64(daily_challenge.seed),
+        _ => StdRng::from_entropy(),
+    };
+}
+
+pub fn apply_daily_modifiers(
+    mut run_modifiers: ResMut<RunModifiers>,
+    daily_challenge: Res<DailyChallenge>,
+) {
+    run_modifiers.active = daily_challenge.modifiers.clone();
+    println!(
+        "Daily challenge {}: {}",
+        daily_challenge.date,
+        daily_challenge.describe_modifiers()
+    );
+}
@@ -1,6 +1,97 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ENEMY_SIZE;
+use crate::game::collision::components::PreviousPosition;
+use crate::game::level::loader::EnemyPlacement;
+use crate::game::spawner::components::Footprint;
 
 #[derive(Component)]
 pub struct Enemy {
     pub direction: Vec2,
+    // Constant unless something like a force field speeds it up or slows it down.
+    pub speed: f32,
+    // What it spawned with; force fields keep `speed` within a range of this.
+    pub base_speed: f32,
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum EnemyKind {
+    #[default]
+    Bouncer,
+}
+
+impl EnemyKind {
+    pub const ALL: [EnemyKind; 1] = [EnemyKind::Bouncer];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyKind::Bouncer => "Bouncer",
+        }
+    }
+
+    pub fn sprite(&self) -> &'static str {
+        match self {
+            EnemyKind::Bouncer => "sprites/ball_red_large.png",
+        }
+    }
+}
+
+// What a new enemy asks for; anything left out is rolled from the kind's
+// `EnemyKindConfig`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnemySpawn {
+    pub kind: EnemyKind,
+    pub direction: Option<Vec2>,
+    pub speed: Option<f32>,
+    // Heads for the nearest player when `direction` is unset.
+    pub aimed: bool,
+}
+
+impl From<&EnemyPlacement> for EnemySpawn {
+    fn from(placement: &EnemyPlacement) -> EnemySpawn {
+        EnemySpawn {
+            kind: placement.kind,
+            direction: placement.direction.map(|(x, y)| Vec2::new(x, y)),
+            speed: placement.speed,
+            aimed: placement.aimed,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct EnemyBundle {
+    pub sprite_bundle: SpriteBundle,
+    pub enemy: Enemy,
+    pub kind: EnemyKind,
+    pub previous_position: PreviousPosition,
+    pub footprint: Footprint,
+}
+
+impl EnemyBundle {
+    pub fn new(
+        kind: EnemyKind,
+        position: Vec2,
+        direction: Vec2,
+        speed: f32,
+        texture: Handle<Image>,
+    ) -> EnemyBundle {
+        EnemyBundle {
+            sprite_bundle: SpriteBundle {
+                transform: Transform::from_translation(position.extend(0.0)),
+                texture,
+                ..default()
+            },
+            enemy: Enemy {
+                direction,
+                speed,
+                base_speed: speed,
+            },
+            kind,
+            previous_position: PreviousPosition { value: position },
+            footprint: Footprint {
+                radius: ENEMY_SIZE / 2.0,
+            },
+        }
+    }
 }
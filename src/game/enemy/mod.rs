@@ -7,8 +7,16 @@ mod systems;
 use resources::*;
 use systems::*;
 
+pub use systems::spawn_velocity;
+
+use crate::events::LevelStarted;
 use crate::AppState;
 
+use super::mode::mode_has_enemies;
+use super::modifiers::arena_has_walls;
+use super::network::is_authority;
+use super::player::{ConfinementSystemSet, MovementSystemSet};
+use super::spawner::SpawnSystemSet;
 use super::SimulationState;
 
 pub const NUMBER_OF_ENEMIES: usize = 4;
@@ -20,8 +28,25 @@ pub struct EnemyPlugin;
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<EnemySpawnTimer>()
+            .init_resource::<EnemyWave>()
             // .add_startup_system(spawn_enemies)
-            .add_system(spawn_enemies.in_schedule(OnEnter(AppState::Game)))
+            .add_system(
+                start_enemy_waves
+                    .in_set(OnUpdate(AppState::Game))
+                    .run_if(is_authority)
+                    .run_if(mode_has_enemies)
+                    .run_if(on_event::<LevelStarted>()),
+            )
+            // Not held back by the pause, so the opening wave is there as the
+            // level appears.
+            .add_system(
+                spawn_wave_enemies
+                    .in_set(OnUpdate(AppState::Game))
+                    .run_if(is_authority)
+                    .after(start_enemy_waves)
+                    .after(advance_enemy_wave)
+                    .before(SpawnSystemSet),
+            )
             // .add_system(enemy_movement)
             // .add_system(update_enemy_direction)
             // .add_system(confine_enemy_movement)
@@ -29,14 +54,19 @@ impl Plugin for EnemyPlugin {
             // .add_system(spawn_enemies_over_time);
             .add_systems(
                 (
-                    enemy_movement,
-                    update_enemy_direction,
-                    confine_enemy_movement,
+                    enemy_movement.in_set(MovementSystemSet),
+                    update_enemy_direction
+                        .in_set(ConfinementSystemSet)
+                        .run_if(arena_has_walls),
+                    confine_enemy_movement
+                        .in_set(ConfinementSystemSet)
+                        .run_if(arena_has_walls),
                     tick_enemy_spawn_timer,
-                    spawn_enemies_over_time,
+                    advance_enemy_wave.run_if(mode_has_enemies),
                 )
                     .in_set(OnUpdate(AppState::Game))
-                    .in_set(OnUpdate(SimulationState::Running)),
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
             )
             .add_system(despawn_enemies.in_schedule(OnExit(AppState::Game)));
     }
@@ -14,3 +14,9 @@ impl Default for EnemySpawnTimer {
         }
     }
 }
+
+// Every timed spawn counts as a new wave; the opening spawn is wave 1.
+#[derive(Resource, Default)]
+pub struct EnemyWave {
+    pub number: u32,
+}
@@ -1,3 +1,6 @@
+use std::f32::consts::TAU;
+use std::time::Duration;
+
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use rand::prelude::*;
@@ -5,30 +8,77 @@ use rand::prelude::*;
 // use crate::enemy::components::*;
 use super::components::*;
 use super::resources::*;
-use super::{ENEMY_SIZE, ENEMY_SPEED, NUMBER_OF_ENEMIES};
+use super::ENEMY_SIZE;
+use crate::config::EnemyKindConfig;
+use crate::events::{EnemyBounced, SpawnRequest, WaveStarted};
+use crate::game::collision::MAX_WALL_BOUNCES;
+use crate::game::level::loader::EnemyPlacement;
+use crate::game::level::resources::ActiveLevel;
+use crate::game::modifiers::resources::{Modifier, RunModifiers};
+use crate::game::spawner::components::{Placement, SpawnKind};
 
-pub fn spawn_enemies(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+// The opening placement is wave 1; it is spawned like any other wave.
+pub fn start_enemy_waves(
+    active_level: Res<ActiveLevel>,
+    mut enemy_spawn_timer: ResMut<EnemySpawnTimer>,
+    mut enemy_wave: ResMut<EnemyWave>,
+    mut wave_started_event_writer: EventWriter<WaveStarted>,
 ) {
-    let window = window_query.get_single().unwrap();
+    enemy_wave.number = 1;
+    wave_started_event_writer.send(WaveStarted { wave: 1 });
 
-    for _ in 0..NUMBER_OF_ENEMIES {
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
-
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/ball_red_large.png"),
-                ..default()
-            },
-            Enemy {
-                direction: Vec2::new(random::<f32>(), random::<f32>()).normalize(),
-            },
-        ));
-    }
+    set_next_wave_delay(&mut enemy_spawn_timer, &active_level, 0);
+    enemy_spawn_timer.timer.reset();
+}
+
+// The requested heading and speed win. Otherwise the speed is rolled from the
+// kind's range and the heading is uniform around the full circle, or aimed at
+// `target` give or take `aim_spread`.
+pub fn spawn_velocity(
+    enemy_spawn: &EnemySpawn,
+    kind_config: &EnemyKindConfig,
+    position: Vec2,
+    target: Option<Vec2>,
+    rng: &mut impl Rng,
+) -> (Vec2, f32) {
+    let (min_speed, max_speed) = kind_config.speed;
+    let speed = enemy_spawn
+        .speed
+        .unwrap_or_else(|| rng.gen_range(min_speed..=max_speed.max(min_speed)));
+
+    let aimed = enemy_spawn.aimed || rng.gen_bool(kind_config.aimed_chance.clamp(0.0, 1.0) as f64);
+    let aim = target
+        .filter(|_| aimed)
+        .and_then(|target| (target - position).try_normalize());
+    let placed = enemy_spawn
+        .direction
+        .and_then(|direction| direction.try_normalize());
+    let direction = match (placed, aim) {
+        (Some(direction), _) => direction,
+        (None, Some(aim)) => {
+            let spread = kind_config.aim_spread.abs().to_radians();
+            Vec2::from_angle(rng.gen_range(-spread..=spread)).rotate(aim)
+        }
+        (None, None) => Vec2::from_angle(rng.gen_range(0.0..TAU)),
+    };
+    (direction, speed)
+}
+
+// Scripted waves each bring their own delay; after the script the usual
+// `ENEMY_SPAWN_TIME` applies.
+fn set_next_wave_delay(
+    enemy_spawn_timer: &mut EnemySpawnTimer,
+    active_level: &ActiveLevel,
+    script_index: usize,
+) {
+    let delay = active_level
+        .level
+        .waves
+        .get(script_index)
+        .map_or(ENEMY_SPAWN_TIME, |wave| wave.delay);
+    enemy_spawn_timer
+        .timer
+        .set_duration(Duration::from_secs_f32(delay));
 }
 
 pub fn despawn_enemies(mut commands: Commands, enemy_query: Query<Entity, With<Enemy>>) {
@@ -37,18 +87,79 @@ pub fn despawn_enemies(mut commands: Commands, enemy_query: Query<Entity, With<E
     }
 }
 
-pub fn enemy_movement(mut enemy_query: Query<(&mut Transform, &Enemy)>, time: Res<Time>) {
-    for (mut transform, enemy) in enemy_query.iter_mut() {
-        let direction = Vec3::new(enemy.direction.x, enemy.direction.y, 0.0);
-        transform.translation += direction * ENEMY_SPEED * time.delta_seconds();
+pub fn enemy_movement(
+    mut enemy_query: Query<(&mut Transform, &mut Enemy)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    run_modifiers: Res<RunModifiers>,
+    time: Res<Time>,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>,
+) {
+    let window = window_query.get_single().unwrap();
+
+    let half_enemy_size = ENEMY_SIZE / 2.0;
+    let min = Vec2::splat(half_enemy_size);
+    let max = Vec2::new(window.width(), window.height()) - half_enemy_size;
+
+    let has_walls = !run_modifiers.has(Modifier::NoWalls);
+    let speed_multiplier = run_modifiers.enemy_speed_multiplier();
+    for (mut transform, mut enemy) in enemy_query.iter_mut() {
+        let mut position = transform.translation.truncate();
+        let mut distance = enemy.speed * speed_multiplier * time.delta_seconds();
+
+        // Bounce at the exact point the ball reaches a wall, however far it
+        // travels this frame, instead of letting it tunnel out and clamping.
+        for _ in 0..MAX_WALL_BOUNCES {
+            let Some((travelled, normal)) = has_walls
+                .then(|| wall_impact(position, enemy.direction, distance, min, max))
+                .flatten()
+            else {
+                break;
+            };
+
+            position += enemy.direction * travelled;
+            distance -= travelled;
+            let approach = enemy.direction.dot(normal);
+            enemy.direction -= 2.0 * approach * normal;
+            enemy_bounced_event_writer.send(EnemyBounced { position, normal });
+        }
+
+        position += enemy.direction * distance;
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
+
+// How far a ball can move along `direction` before touching a wall, and the
+// normal pointing back into the arena from it, if that's within `distance`.
+fn wall_impact(
+    position: Vec2,
+    direction: Vec2,
+    distance: f32,
+    min: Vec2,
+    max: Vec2,
+) -> Option<(f32, Vec2)> {
+    let mut impact: Option<(f32, Vec2)> = None;
+    for (travel, normal) in [
+        (direction.x < 0.0).then(|| ((min.x - position.x) / direction.x, Vec2::X)),
+        (direction.x > 0.0).then(|| ((max.x - position.x) / direction.x, Vec2::NEG_X)),
+        (direction.y < 0.0).then(|| ((min.y - position.y) / direction.y, Vec2::Y)),
+        (direction.y > 0.0).then(|| ((max.y - position.y) / direction.y, Vec2::NEG_Y)),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        // Balls already outside are left for `update_enemy_direction`.
+        let closer = impact.is_none_or(|(closest, _)| travel < closest);
+        if travel >= 0.0 && travel <= distance && closer {
+            impact = Some((travel, normal));
+        }
     }
+    impact
 }
 
 pub fn update_enemy_direction(
     mut enemy_query: Query<(&Transform, &mut Enemy)>,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    // audio: Res<Audio>,
-    // asset_server: Res<AssetServer>,
+    mut enemy_bounced_event_writer: EventWriter<EnemyBounced>,
 ) {
     let window = window_query.get_single().unwrap();
 
@@ -59,31 +170,25 @@ pub fn update_enemy_direction(
     let y_max = window.height() - half_enemy_size;
 
     for (transform, mut enemy) in enemy_query.iter_mut() {
-        // let mut direction_changed = false;
+        // Points back into the arena from whichever wall(s) were hit.
+        let mut normal = Vec2::ZERO;
 
         let translation = transform.translation;
         if translation.x < x_min || translation.x > x_max {
             enemy.direction.x *= -1.0;
-            // direction_changed = true;
+            normal.x = if translation.x < x_min { 1.0 } else { -1.0 };
         }
         if translation.y < y_min || translation.y > y_max {
             enemy.direction.y *= -1.0;
-            // direction_changed = true;
+            normal.y = if translation.y < y_min { 1.0 } else { -1.0 };
         }
 
-        // Play SFX
-        // if direction_changed {
-        //     // Play Sound Effect
-        //     let sound_effect_1 = asset_server.load("audio/pluck_001.ogg");
-        //     let sound_effect_2 = asset_server.load("audio/pluck_002.ogg");
-        //     // Randomly play one of the two sound effects.
-        //     let sound_effect = if random::<f32>() > 0.5 {
-        //         sound_effect_1
-        //     } else {
-        //         sound_effect_2
-        //     };
-        //     audio.play(sound_effect);
-        // }
+        if normal != Vec2::ZERO {
+            enemy_bounced_event_writer.send(EnemyBounced {
+                position: translation.truncate(),
+                normal: normal.normalize(),
+            });
+        }
     }
 }
 
@@ -123,27 +228,151 @@ pub fn tick_enemy_spawn_timer(mut enemy_spawn_timer: ResMut<EnemySpawnTimer>, ti
     enemy_spawn_timer.timer.tick(time.delta());
 }
 
-pub fn spawn_enemies_over_time(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    enemy_spawn_timer: Res<EnemySpawnTimer>,
+// Every timed spawn counts as a new wave.
+pub fn advance_enemy_wave(
+    active_level: Res<ActiveLevel>,
+    mut enemy_spawn_timer: ResMut<EnemySpawnTimer>,
+    mut enemy_wave: ResMut<EnemyWave>,
+    mut wave_started_event_writer: EventWriter<WaveStarted>,
 ) {
     if enemy_spawn_timer.timer.finished() {
-        let window = window_query.get_single().unwrap();
-
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
-
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/ball_red_large.png"),
-                ..default()
-            },
-            Enemy {
-                direction: Vec2::new(random::<f32>(), random::<f32>()).normalize(),
-            },
-        ));
+        // Wave 1 is the opening placement, so wave n + 1 is script entry n - 1.
+        let script_index = (enemy_wave.number as usize).saturating_sub(1);
+        enemy_wave.number += 1;
+        wave_started_event_writer.send(WaveStarted {
+            wave: enemy_wave.number,
+        });
+
+        set_next_wave_delay(&mut enemy_spawn_timer, &active_level, script_index + 1);
+    }
+}
+
+// Wave 1 is the level's opening placement, later waves follow its script and
+// once that runs out each wave is a single random enemy.
+pub fn spawn_wave_enemies(
+    active_level: Res<ActiveLevel>,
+    mut wave_started_event_reader: EventReader<WaveStarted>,
+    mut spawn_request_event_writer: EventWriter<SpawnRequest>,
+) {
+    for event in wave_started_event_reader.iter() {
+        let placements = match event.wave {
+            1 => active_level.level.enemies.clone(),
+            wave => active_level.level.waves.get(wave as usize - 2).map_or_else(
+                || vec![EnemyPlacement::default()],
+                |wave| wave.enemies.clone(),
+            ),
+        };
+
+        for placement in placements.iter() {
+            spawn_request_event_writer.send(SpawnRequest {
+                kind: SpawnKind::Enemy(placement.into()),
+                placement: match placement.position {
+                    Some(position) => Placement::At(active_level.to_world(position)),
+                    None => Placement::Anywhere,
+                },
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    fn kind_config(aimed_chance: f32, aim_spread: f32) -> EnemyKindConfig {
+        EnemyKindConfig {
+            speed: (100.0, 300.0),
+            aimed_chance,
+            aim_spread,
+        }
+    }
+
+    #[test]
+    fn random_headings_cover_the_full_circle() {
+        let mut rng = StdRng::seed_from_u64(48);
+        let mut quadrants = [0; 4];
+
+        for _ in 0..4000 {
+            let (direction, speed) = spawn_velocity(
+                &EnemySpawn::default(),
+                &kind_config(0.0, 0.0),
+                Vec2::ZERO,
+                None,
+                &mut rng,
+            );
+            assert!(direction.is_finite() && (direction.length() - 1.0).abs() < 1e-4);
+            assert!((100.0..=300.0).contains(&speed));
+            quadrants[usize::from(direction.x < 0.0) + 2 * usize::from(direction.y < 0.0)] += 1;
+        }
+
+        assert!(
+            quadrants.iter().all(|count| *count > 800),
+            "{:?}",
+            quadrants
+        );
+    }
+
+    #[test]
+    fn requested_heading_and_speed_win() {
+        let enemy_spawn = EnemySpawn {
+            direction: Some(Vec2::new(0.0, -3.0)),
+            speed: Some(50.0),
+            aimed: true,
+            ..default()
+        };
+        let (direction, speed) = spawn_velocity(
+            &enemy_spawn,
+            &kind_config(1.0, 0.0),
+            Vec2::ZERO,
+            Some(Vec2::new(100.0, 0.0)),
+            &mut StdRng::seed_from_u64(48),
+        );
+
+        assert_eq!(direction, Vec2::NEG_Y);
+        assert_eq!(speed, 50.0);
+    }
+
+    #[test]
+    fn aimed_spawns_head_for_the_target() {
+        let (direction, _) = spawn_velocity(
+            &EnemySpawn::default(),
+            &kind_config(1.0, 0.0),
+            Vec2::new(100.0, 100.0),
+            Some(Vec2::new(100.0, 300.0)),
+            &mut StdRng::seed_from_u64(48),
+        );
+
+        assert!((direction - Vec2::Y).length() < 1e-4);
+    }
+
+    #[test]
+    fn degenerate_input_never_gives_nan() {
+        let mut rng = StdRng::seed_from_u64(48);
+        let zero_direction = EnemySpawn {
+            direction: Some(Vec2::ZERO),
+            aimed: true,
+            ..default()
+        };
+        // A zero placed heading, a target on top of the spawn and a reversed
+        // speed range all fall back to something usable.
+        let kind_config = EnemyKindConfig {
+            speed: (300.0, 100.0),
+            aimed_chance: 1.0,
+            aim_spread: -30.0,
+        };
+
+        for _ in 0..100 {
+            let (direction, speed) = spawn_velocity(
+                &zero_direction,
+                &kind_config,
+                Vec2::ONE,
+                Some(Vec2::ONE),
+                &mut rng,
+            );
+            assert!(direction.is_finite() && (direction.length() - 1.0).abs() < 1e-4);
+            assert_eq!(speed, 300.0);
+        }
     }
 }
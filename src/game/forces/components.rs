@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Component)]
+pub struct ForceField {
+    pub kind: ForceFieldKind,
+}
+
+// Accelerates every ball inside it. Strengths are in pixels per second squared.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum ForceFieldKind {
+    // Pulls towards the centre, strongest there and fading out at `radius`.
+    Attractor { radius: f32, strength: f32 },
+    // Pushes away from the centre, with the same falloff.
+    Repulsor { radius: f32, strength: f32 },
+    // A steady push in `force` across an axis-aligned box.
+    Wind { size: (f32, f32), force: (f32, f32) },
+}
+
+impl ForceFieldKind {
+    // Level files are laid out for their own arena size; stretch to the window.
+    pub fn scaled(&self, scale: Vec2) -> ForceFieldKind {
+        let radius_scale = scale.min_element();
+        match *self {
+            ForceFieldKind::Attractor { radius, strength } => ForceFieldKind::Attractor {
+                radius: radius * radius_scale,
+                strength,
+            },
+            ForceFieldKind::Repulsor { radius, strength } => ForceFieldKind::Repulsor {
+                radius: radius * radius_scale,
+                strength,
+            },
+            ForceFieldKind::Wind { size, force } => ForceFieldKind::Wind {
+                size: (size.0 * scale.x, size.1 * scale.y),
+                force,
+            },
+        }
+    }
+
+    // The acceleration on a ball at `position` from this field centred on `centre`.
+    pub fn acceleration(&self, centre: Vec2, position: Vec2) -> Vec2 {
+        match *self {
+            ForceFieldKind::Attractor { radius, strength } => {
+                radial_falloff(centre, position, radius) * -strength
+            }
+            ForceFieldKind::Repulsor { radius, strength } => {
+                radial_falloff(centre, position, radius) * strength
+            }
+            ForceFieldKind::Wind { size, force } => {
+                let half_size = Vec2::new(size.0, size.1) / 2.0;
+                let offset = (position - centre).abs();
+                if offset.x <= half_size.x && offset.y <= half_size.y {
+                    Vec2::new(force.0, force.1)
+                } else {
+                    Vec2::ZERO
+                }
+            }
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            ForceFieldKind::Attractor { radius, .. } | ForceFieldKind::Repulsor { radius, .. } => {
+                radius > 0.0
+            }
+            ForceFieldKind::Wind { size, .. } => size.0 > 0.0 && size.1 > 0.0,
+        }
+    }
+}
+
+// Outward unit vector scaled from 1 at the centre down to 0 at `radius`.
+fn radial_falloff(centre: Vec2, position: Vec2, radius: f32) -> Vec2 {
+    let offset = position - centre;
+    let distance = offset.length();
+    if distance >= radius {
+        return Vec2::ZERO;
+    }
+    offset.normalize_or_zero() * (1.0 - distance / radius)
+}
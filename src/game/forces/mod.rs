@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use systems::*;
+
+use crate::events::LevelStarted;
+use crate::AppState;
+
+use super::network::is_authority;
+use super::player::MovementSystemSet;
+use super::SimulationState;
+
+pub const FORCE_FIELD_Z: f32 = -0.6; // Drawn under the obstacles.
+
+// Forces change how fast enemies go, within these multiples of their own speed.
+pub const ENEMY_MIN_SPEED_FACTOR: f32 = 0.5;
+pub const ENEMY_MAX_SPEED_FACTOR: f32 = 2.5;
+
+pub struct ForcesPlugin;
+
+impl Plugin for ForcesPlugin {
+    fn build(&self, app: &mut App) {
+        // Like obstacles, every peer draws the fields from its own level copy.
+        app.add_system(
+            spawn_force_fields
+                .in_set(OnUpdate(AppState::Game))
+                .run_if(on_event::<LevelStarted>()),
+        )
+        .add_systems(
+            (apply_forces_to_enemies, apply_forces_to_players)
+                .before(MovementSystemSet)
+                .in_set(OnUpdate(AppState::Game))
+                .in_set(OnUpdate(SimulationState::Running))
+                .distributive_run_if(is_authority),
+        )
+        .add_system(despawn_force_fields.in_schedule(OnExit(AppState::Game)));
+    }
+}
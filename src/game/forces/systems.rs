@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use super::components::{ForceField, ForceFieldKind};
+use super::{ENEMY_MAX_SPEED_FACTOR, ENEMY_MIN_SPEED_FACTOR, FORCE_FIELD_Z};
+use crate::game::enemy::components::Enemy;
+use crate::game::level::resources::ActiveLevel;
+use crate::game::network::components::RemotePlayer;
+use crate::game::player::components::{Player, Velocity};
+
+const ATTRACTOR_COLOUR: Color = Color::rgba(0.3, 0.5, 1.0, 0.15);
+const REPULSOR_COLOUR: Color = Color::rgba(1.0, 0.35, 0.3, 0.15);
+const WIND_COLOUR: Color = Color::rgba(0.8, 0.9, 1.0, 0.1);
+
+type PlayerFilter = Or<(With<Player>, With<RemotePlayer>)>;
+
+pub fn spawn_force_fields(
+    mut commands: Commands,
+    active_level: Res<ActiveLevel>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for force_field in active_level.level.force_fields.iter() {
+        let kind = force_field.kind.scaled(active_level.scale);
+        let transform = Transform::from_translation(
+            active_level
+                .to_world(force_field.position)
+                .extend(FORCE_FIELD_Z),
+        );
+
+        match kind {
+            ForceFieldKind::Attractor { radius, .. } | ForceFieldKind::Repulsor { radius, .. } => {
+                let colour = match kind {
+                    ForceFieldKind::Attractor { .. } => ATTRACTOR_COLOUR,
+                    _ => REPULSOR_COLOUR,
+                };
+                commands.spawn((
+                    MaterialMesh2dBundle {
+                        transform,
+                        mesh: meshes.add(shape::Circle::new(radius).into()).into(),
+                        material: materials.add(ColorMaterial::from(colour)),
+                        ..default()
+                    },
+                    ForceField { kind },
+                ));
+            }
+            ForceFieldKind::Wind { size, .. } => {
+                commands.spawn((
+                    SpriteBundle {
+                        transform,
+                        sprite: Sprite {
+                            color: WIND_COLOUR,
+                            custom_size: Some(Vec2::new(size.0, size.1)),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    ForceField { kind },
+                ));
+            }
+        }
+    }
+}
+
+pub fn despawn_force_fields(
+    mut commands: Commands,
+    force_field_query: Query<Entity, With<ForceField>>,
+) {
+    for force_field_entity in force_field_query.iter() {
+        commands.entity(force_field_entity).despawn();
+    }
+}
+
+// Gravity plus every field the ball is inside.
+fn acceleration_at(
+    position: Vec2,
+    active_level: &ActiveLevel,
+    force_field_query: &Query<(&Transform, &ForceField)>,
+) -> Vec2 {
+    let gravity = active_level
+        .level
+        .gravity
+        .map_or(Vec2::ZERO, |(x, y)| Vec2::new(x, y));
+    force_field_query
+        .iter()
+        .fold(gravity, |acceleration, (field_transform, force_field)| {
+            acceleration
+                + force_field
+                    .kind
+                    .acceleration(field_transform.translation.truncate(), position)
+        })
+}
+
+// Enemies have no velocity of their own, just a heading and a speed, so the
+// force bends one and speeds up or slows down the other.
+pub fn apply_forces_to_enemies(
+    mut enemy_query: Query<(&Transform, &mut Enemy)>,
+    force_field_query: Query<(&Transform, &ForceField)>,
+    active_level: Res<ActiveLevel>,
+    time: Res<Time>,
+) {
+    for (transform, mut enemy) in enemy_query.iter_mut() {
+        let acceleration = acceleration_at(
+            transform.translation.truncate(),
+            &active_level,
+            &force_field_query,
+        );
+        if acceleration == Vec2::ZERO {
+            continue;
+        }
+
+        let velocity = enemy.direction * enemy.speed + acceleration * time.delta_seconds();
+        if let Some(direction) = velocity.try_normalize() {
+            enemy.direction = direction;
+        }
+        enemy.speed = velocity.length().clamp(
+            enemy.base_speed * ENEMY_MIN_SPEED_FACTOR,
+            enemy.base_speed * ENEMY_MAX_SPEED_FACTOR,
+        );
+    }
+}
+
+// Steering still caps the player's speed, and without momentum it replaces the
+// velocity every frame, so fields only push players who have momentum on.
+pub fn apply_forces_to_players(
+    mut player_query: Query<(&Transform, &mut Velocity), PlayerFilter>,
+    force_field_query: Query<(&Transform, &ForceField)>,
+    active_level: Res<ActiveLevel>,
+    time: Res<Time>,
+) {
+    for (transform, mut velocity) in player_query.iter_mut() {
+        velocity.value += acceleration_at(
+            transform.translation.truncate(),
+            &active_level,
+            &force_field_query,
+        ) * time.delta_seconds();
+    }
+}
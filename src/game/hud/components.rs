@@ -0,0 +1,10 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct Hud {}
+
+#[derive(Component)]
+pub struct DashMeterFill {}
+
+#[derive(Component)]
+pub struct ObjectiveText {}
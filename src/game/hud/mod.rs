@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+mod components;
+mod styles;
+mod systems;
+
+use systems::*;
+
+use crate::AppState;
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_hud.in_schedule(OnEnter(AppState::Game)))
+            .add_systems(
+                (update_dash_meter, update_objective_text).in_set(OnUpdate(AppState::Game)),
+            )
+            .add_system(despawn_hud.in_schedule(OnExit(AppState::Game)));
+    }
+}
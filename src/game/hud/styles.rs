@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+pub const METER_BACKGROUND_COLOUR: Color = Color::rgba(0.1, 0.1, 0.1, 0.8);
+pub const DASH_CHARGING_COLOUR: Color = Color::rgb(0.4, 0.4, 0.4);
+pub const DASH_READY_COLOUR: Color = Color::rgb(0.25, 0.45, 0.85);
+
+pub const HUD_STYLE: Style = Style {
+    position_type: PositionType::Absolute,
+    position: UiRect {
+        left: Val::Px(16.),
+        bottom: Val::Px(16.),
+        ..UiRect::DEFAULT
+    },
+    flex_direction: FlexDirection::Row,
+    align_items: AlignItems::Center,
+    gap: Size::new(Val::Px(8.), Val::Px(8.)),
+    ..Style::DEFAULT
+};
+
+pub const METER_STYLE: Style = Style {
+    size: Size::new(Val::Px(120.), Val::Px(12.)),
+    ..Style::DEFAULT
+};
+
+pub fn get_meter_fill_style(fraction: f32) -> Style {
+    Style {
+        size: Size::new(Val::Percent(fraction * 100.0), Val::Percent(100.0)),
+        ..Style::DEFAULT
+    }
+}
+
+pub fn get_label_text_style(game_assets: &GameAssets) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 20.,
+        color: Color::WHITE,
+    }
+}
@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+
+use super::components::{DashMeterFill, Hud, ObjectiveText};
+use super::styles::*;
+use crate::assets::GameAssets;
+use crate::config::GameConfig;
+use crate::game::daily::resources::DailyChallenge;
+use crate::game::level::loader::WinCondition;
+use crate::game::level::resources::{ActiveLevel, LevelRun};
+use crate::game::mode::resources::GameMode;
+use crate::game::player::components::{Dash, Player};
+use crate::game::score::resources::Score;
+
+pub fn spawn_hud(mut commands: Commands, game_assets: Res<GameAssets>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: HUD_STYLE,
+                ..default()
+            },
+            Hud {},
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section("", get_label_text_style(&game_assets)),
+                ObjectiveText {},
+            ));
+            parent.spawn(TextBundle::from_section(
+                "Dash",
+                get_label_text_style(&game_assets),
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: METER_STYLE,
+                    background_color: METER_BACKGROUND_COLOUR.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: get_meter_fill_style(1.0),
+                            background_color: DASH_READY_COLOUR.into(),
+                            ..default()
+                        },
+                        DashMeterFill {},
+                    ));
+                });
+        });
+}
+
+pub fn despawn_hud(mut commands: Commands, hud_query: Query<Entity, With<Hud>>) {
+    if let Ok(hud_entity) = hud_query.get_single() {
+        commands.entity(hud_entity).despawn_recursive();
+    }
+}
+
+// Fills up as the dash cooldown runs down.
+pub fn update_dash_meter(
+    player_query: Query<&Dash, With<Player>>,
+    mut fill_query: Query<(&mut Style, &mut BackgroundColor), With<DashMeterFill>>,
+    game_config: Res<GameConfig>,
+) {
+    let (Ok(dash), Ok((mut style, mut background_colour))) =
+        (player_query.get_single(), fill_query.get_single_mut())
+    else {
+        return;
+    };
+
+    let cooldown = game_config.player.dash.cooldown;
+    let charged = if cooldown > 0.0 {
+        (1.0 - dash.cooldown_remaining / cooldown).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    *style = get_meter_fill_style(charged);
+    *background_colour = if dash.is_ready() {
+        DASH_READY_COLOUR.into()
+    } else {
+        DASH_CHARGING_COLOUR.into()
+    };
+}
+
+// Progress towards the level's win condition, or the mode's clock when the
+// level has none.
+pub fn update_objective_text(
+    mut text_query: Query<&mut Text, With<ObjectiveText>>,
+    active_level: Res<ActiveLevel>,
+    level_run: Res<LevelRun>,
+    game_mode: Res<GameMode>,
+    daily_challenge: Res<DailyChallenge>,
+    score: Res<Score>,
+) {
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+
+    let progress = match active_level.level.win_condition {
+        _ if !level_run.started => String::new(),
+        Some(WinCondition::Score(target)) => format!("Score {} / {}", score.value, target),
+        Some(WinCondition::SurviveSeconds(target)) => {
+            format!(
+                "Survive {:.0} / {:.0}s",
+                level_run.elapsed.min(target),
+                target
+            )
+        }
+        Some(WinCondition::CollectStars(target)) => {
+            format!("Stars {} / {}", level_run.stars.min(target), target)
+        }
+        None => match *game_mode {
+            GameMode::TimeAttack => {
+                let time_limit = game_mode.time_limit().unwrap_or_default();
+                format!("Time {:.0}s", (time_limit - level_run.elapsed).max(0.0))
+            }
+            GameMode::Survival => format!("Alive {:.0}s", level_run.elapsed.floor()),
+            GameMode::DailyChallenge => {
+                format!(
+                    "Daily {}: {}",
+                    daily_challenge.date,
+                    daily_challenge.describe_modifiers()
+                )
+            }
+            GameMode::Endless | GameMode::Zen => String::new(),
+        },
+    };
+    if text.sections[0].value != progress {
+        text.sections[0].value = progress;
+    }
+}
@@ -0,0 +1,436 @@
+use bevy::asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy::reflect::TypeUuid;
+use bevy::utils::BoxedFuture;
+use serde::{Deserialize, Serialize};
+
+use crate::game::arena::components::ObstacleKind;
+use crate::game::enemy::components::EnemyKind;
+use crate::game::enemy::NUMBER_OF_ENEMIES;
+use crate::game::forces::components::ForceFieldKind;
+
+// One arena, read from a `.level.ron` file under `assets/levels/`. Positions
+// are in pixels from the bottom-left corner of an `arena_size` arena and are
+// stretched to fit the window.
+#[derive(Serialize, Deserialize, TypeUuid, Debug, Clone)]
+#[uuid = "7a1f08ba-e479-4408-b220-150181678c4d"]
+#[serde(default)]
+pub struct Level {
+    pub name: String,
+    pub arena_size: (f32, f32),
+    // `None` starts the player in the middle of the arena.
+    pub player_start: Option<(f32, f32)>,
+    pub obstacles: Vec<ObstacleLayout>,
+    // Pulls the player and enemies, e.g. `Some((0.0, -300.0))` pulls them down.
+    pub gravity: Option<(f32, f32)>,
+    pub force_fields: Vec<ForceFieldLayout>,
+    // The opening wave.
+    pub enemies: Vec<EnemyPlacement>,
+    // Stars only appear inside these; none means anywhere in the arena.
+    pub star_regions: Vec<StarRegion>,
+    // Played in order after the opening wave. Once they run out a random enemy
+    // arrives every `ENEMY_SPAWN_TIME` as usual.
+    pub waves: Vec<WaveScript>,
+    // `None` is an endless run that only ends when the player is hit.
+    pub win_condition: Option<WinCondition>,
+}
+
+impl Default for Level {
+    fn default() -> Level {
+        Level {
+            name: "Open Arena".to_string(),
+            arena_size: (1280.0, 720.0),
+            player_start: None,
+            obstacles: Vec::new(),
+            gravity: None,
+            force_fields: Vec::new(),
+            enemies: vec![EnemyPlacement::default(); NUMBER_OF_ENEMIES],
+            star_regions: Vec::new(),
+            waves: Vec::new(),
+            win_condition: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ObstacleLayout {
+    pub kind: ObstacleKind,
+    pub position: (f32, f32),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ForceFieldLayout {
+    pub kind: ForceFieldKind,
+    pub position: (f32, f32),
+}
+
+// Anything left out is picked at random.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+#[serde(default)]
+pub struct EnemyPlacement {
+    pub kind: EnemyKind,
+    pub position: Option<(f32, f32)>,
+    pub direction: Option<(f32, f32)>,
+    // Heads for the player instead of a random direction when `direction` is unset.
+    pub aimed: bool,
+    pub speed: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct StarRegion {
+    pub min: (f32, f32),
+    pub max: (f32, f32),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WaveScript {
+    // Seconds after the previous wave.
+    pub delay: f32,
+    pub enemies: Vec<EnemyPlacement>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum WinCondition {
+    Score(u32),
+    SurviveSeconds(f32),
+    CollectStars(u32),
+}
+
+impl WinCondition {
+    pub fn description(&self) -> String {
+        match self {
+            WinCondition::Score(score) => format!("Score {} points", score),
+            WinCondition::SurviveSeconds(seconds) => format!("Survive {} seconds", seconds),
+            WinCondition::CollectStars(stars) => format!("Collect {} stars", stars),
+        }
+    }
+}
+
+impl Level {
+    // Everything wrong with the level, so a designer can fix it in one pass.
+    pub fn validate(&self) -> Vec<String> {
+        // NaN slips past every range check below, so catch it first.
+        let mut errors: Vec<String> = self
+            .numbers()
+            .into_iter()
+            .filter(|(_, value)| !value.is_finite())
+            .map(|(name, _)| format!("{} must be a finite number", name))
+            .collect();
+        errors.dedup();
+        if !errors.is_empty() {
+            return errors;
+        }
+
+        let (width, height) = self.arena_size;
+        let inside = |(x, y): (f32, f32)| (0.0..=width).contains(&x) && (0.0..=height).contains(&y);
+
+        if width <= 0.0 || height <= 0.0 {
+            errors.push(format!("arena_size {:?} must be positive", self.arena_size));
+        }
+        if let Some(position) = self.player_start.filter(|position| !inside(*position)) {
+            errors.push(format!("player_start {:?} is outside the arena", position));
+        }
+
+        for (index, obstacle) in self.obstacles.iter().enumerate() {
+            if !inside(obstacle.position) {
+                errors.push(format!(
+                    "obstacle {} at {:?} is outside the arena",
+                    index, obstacle.position
+                ));
+            }
+            let valid = match obstacle.kind {
+                ObstacleKind::Wall { size } => size.0 > 0.0 && size.1 > 0.0,
+                ObstacleKind::Pillar { radius } | ObstacleKind::Bumper { radius, .. } => {
+                    radius > 0.0
+                }
+            };
+            if !valid {
+                errors.push(format!("obstacle {} must have a positive size", index));
+            }
+        }
+
+        for (index, force_field) in self.force_fields.iter().enumerate() {
+            if !inside(force_field.position) {
+                errors.push(format!(
+                    "force field {} at {:?} is outside the arena",
+                    index, force_field.position
+                ));
+            }
+            if !force_field.kind.is_valid() {
+                errors.push(format!("force field {} must have a positive size", index));
+            }
+        }
+
+        let waves = std::iter::once(("opening wave".to_string(), &self.enemies)).chain(
+            self.waves
+                .iter()
+                .enumerate()
+                .map(|(index, wave)| (format!("wave {}", index + 1), &wave.enemies)),
+        );
+        for (wave_name, enemies) in waves {
+            for (index, enemy) in enemies.iter().enumerate() {
+                if let Some(position) = enemy.position.filter(|position| !inside(*position)) {
+                    errors.push(format!(
+                        "{} enemy {} at {:?} is outside the arena",
+                        wave_name, index, position
+                    ));
+                }
+                if enemy.direction == Some((0.0, 0.0)) {
+                    errors.push(format!(
+                        "{} enemy {} has a zero direction",
+                        wave_name, index
+                    ));
+                }
+                if enemy.speed.is_some_and(|speed| speed <= 0.0) {
+                    errors.push(format!(
+                        "{} enemy {} needs a positive speed",
+                        wave_name, index
+                    ));
+                }
+            }
+        }
+
+        for (index, wave) in self.waves.iter().enumerate() {
+            if wave.delay <= 0.0 {
+                errors.push(format!("wave {} needs a positive delay", index + 1));
+            }
+        }
+
+        for (index, region) in self.star_regions.iter().enumerate() {
+            if region.min.0 >= region.max.0 || region.min.1 >= region.max.1 {
+                errors.push(format!("star region {} has min not below max", index));
+            } else if !inside(region.min) || !inside(region.max) {
+                errors.push(format!("star region {} is outside the arena", index));
+            }
+        }
+
+        let valid_win_condition = match self.win_condition {
+            Some(WinCondition::Score(score)) => score > 0,
+            Some(WinCondition::SurviveSeconds(seconds)) => seconds > 0.0,
+            Some(WinCondition::CollectStars(stars)) => stars > 0,
+            None => true,
+        };
+        if !valid_win_condition {
+            errors.push("win_condition target must be above zero".to_string());
+        }
+
+        errors
+    }
+
+    // Every number in the level, named as in the errors above.
+    fn numbers(&self) -> Vec<(String, f32)> {
+        let mut numbers = Vec::new();
+        let mut add = |name: String, values: &[f32]| {
+            numbers.extend(values.iter().map(|value| (name.clone(), *value)));
+        };
+
+        add(
+            "arena_size".to_string(),
+            &[self.arena_size.0, self.arena_size.1],
+        );
+        if let Some((x, y)) = self.player_start {
+            add("player_start".to_string(), &[x, y]);
+        }
+        if let Some((x, y)) = self.gravity {
+            add("gravity".to_string(), &[x, y]);
+        }
+
+        for (index, obstacle) in self.obstacles.iter().enumerate() {
+            let (x, y) = obstacle.position;
+            let name = format!("obstacle {}", index);
+            match obstacle.kind {
+                ObstacleKind::Wall { size } => add(name, &[x, y, size.0, size.1]),
+                ObstacleKind::Pillar { radius } => add(name, &[x, y, radius]),
+                ObstacleKind::Bumper { radius, kick } => add(name, &[x, y, radius, kick]),
+            }
+        }
+
+        for (index, force_field) in self.force_fields.iter().enumerate() {
+            let (x, y) = force_field.position;
+            let name = format!("force field {}", index);
+            match force_field.kind {
+                ForceFieldKind::Attractor { radius, strength }
+                | ForceFieldKind::Repulsor { radius, strength } => {
+                    add(name, &[x, y, radius, strength])
+                }
+                ForceFieldKind::Wind { size, force } => {
+                    add(name, &[x, y, size.0, size.1, force.0, force.1])
+                }
+            }
+        }
+
+        let waves = std::iter::once(("opening wave".to_string(), &self.enemies)).chain(
+            self.waves
+                .iter()
+                .enumerate()
+                .map(|(index, wave)| (format!("wave {}", index + 1), &wave.enemies)),
+        );
+        for (wave_name, enemies) in waves {
+            for (index, enemy) in enemies.iter().enumerate() {
+                let name = format!("{} enemy {}", wave_name, index);
+                let (x, y) = enemy.position.unwrap_or_default();
+                let (dx, dy) = enemy.direction.unwrap_or_default();
+                add(name, &[x, y, dx, dy, enemy.speed.unwrap_or_default()]);
+            }
+        }
+        for (index, wave) in self.waves.iter().enumerate() {
+            add(format!("wave {} delay", index + 1), &[wave.delay]);
+        }
+
+        for (index, region) in self.star_regions.iter().enumerate() {
+            let values = [region.min.0, region.min.1, region.max.0, region.max.1];
+            add(format!("star region {}", index), &values);
+        }
+        if let Some(WinCondition::SurviveSeconds(seconds)) = self.win_condition {
+            add("win_condition".to_string(), &[seconds]);
+        }
+
+        numbers
+    }
+}
+
+#[derive(Default)]
+pub struct LevelLoader;
+
+impl AssetLoader for LevelLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let path = load_context.path().display().to_string();
+
+            let level: Level = ron::de::from_bytes(bytes).map_err(|error| {
+                println!("Could not read level {}: {}", path, error);
+                error
+            })?;
+
+            let errors = level.validate();
+            if !errors.is_empty() {
+                println!("Level {} has {} problem(s):", path, errors.len());
+                for error in errors.iter() {
+                    println!("  - {}", error);
+                }
+                return Err(bevy::asset::Error::msg(format!("invalid level {}", path)));
+            }
+
+            load_context.set_default_asset(LoadedAsset::new(level));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["level.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bevy::prelude::default;
+
+    use super::*;
+    use crate::config::asset_path;
+
+    #[test]
+    fn default_level_is_valid() {
+        assert_eq!(Level::default().validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn shipped_levels_are_valid() {
+        for entry in fs::read_dir(asset_path("levels")).unwrap() {
+            let path = entry.unwrap().path();
+            let level: Level = ron::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+            assert_eq!(level.validate(), Vec::<String>::new(), "{:?}", path);
+        }
+    }
+
+    #[test]
+    fn reports_every_problem() {
+        let enemy = EnemyPlacement {
+            position: Some((2000.0, 100.0)),
+            direction: Some((0.0, 0.0)),
+            speed: Some(-1.0),
+            ..default()
+        };
+        let level = Level {
+            player_start: Some((-10.0, 100.0)),
+            obstacles: vec![ObstacleLayout {
+                kind: ObstacleKind::Pillar { radius: 0.0 },
+                position: (100.0, 100.0),
+            }],
+            enemies: vec![enemy],
+            waves: vec![WaveScript {
+                delay: 0.0,
+                enemies: Vec::new(),
+            }],
+            star_regions: vec![StarRegion {
+                min: (200.0, 200.0),
+                max: (100.0, 300.0),
+            }],
+            win_condition: Some(WinCondition::Score(0)),
+            ..default()
+        };
+
+        assert_eq!(
+            level.validate(),
+            vec![
+                "player_start (-10.0, 100.0) is outside the arena",
+                "obstacle 0 must have a positive size",
+                "opening wave enemy 0 at (2000.0, 100.0) is outside the arena",
+                "opening wave enemy 0 has a zero direction",
+                "opening wave enemy 0 needs a positive speed",
+                "wave 1 needs a positive delay",
+                "star region 0 has min not below max",
+                "win_condition target must be above zero",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_numbers_that_are_not_finite() {
+        let enemy = EnemyPlacement {
+            speed: Some(f32::NAN),
+            ..default()
+        };
+        let level = Level {
+            obstacles: vec![ObstacleLayout {
+                kind: ObstacleKind::Wall {
+                    size: (f32::INFINITY, 10.0),
+                },
+                position: (100.0, f32::NAN),
+            }],
+            enemies: vec![enemy],
+            waves: vec![WaveScript {
+                delay: f32::NAN,
+                enemies: Vec::new(),
+            }],
+            ..default()
+        };
+
+        assert_eq!(
+            level.validate(),
+            vec![
+                "obstacle 0 must be a finite number",
+                "opening wave enemy 0 must be a finite number",
+                "wave 1 delay must be a finite number",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_an_empty_arena() {
+        let level = Level {
+            arena_size: (0.0, 720.0),
+            enemies: Vec::new(),
+            ..default()
+        };
+
+        assert_eq!(
+            level.validate(),
+            vec!["arena_size (0.0, 720.0) must be positive"]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+pub mod loader;
+pub mod resources;
+mod systems;
+
+use loader::*;
+use resources::*;
+use systems::*;
+
+use crate::AppState;
+
+use super::network::is_authority;
+use super::SimulationState;
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Level>()
+            .add_asset_loader(LevelLoader)
+            .init_resource::<ActiveLevel>()
+            .init_resource::<LevelRun>()
+            .add_startup_system(load_level)
+            .add_system(reset_level_run.in_schedule(OnEnter(AppState::Game)))
+            // Waits for the level asset, so setup that depends on it listens
+            // for `LevelStarted` rather than running on entering the state.
+            .add_system(start_level.in_set(OnUpdate(AppState::Game)))
+            .add_systems(
+                (track_level_run, check_win_condition)
+                    .chain()
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
+            );
+    }
+}
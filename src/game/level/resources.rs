@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use super::loader::{Level, WinCondition};
+use crate::config::GameConfig;
+
+// The level the next run plays: the one picked in the game config, or one
+// handed over by the editor or the campaign. `None` plays the default open arena.
+#[derive(Resource, Default)]
+pub struct CurrentLevel {
+    pub handle: Option<Handle<Level>>,
+    // Replaces the level's own win condition, e.g. a campaign objective.
+    pub objective: Option<WinCondition>,
+}
+
+impl CurrentLevel {
+    pub fn from_config(asset_server: &AssetServer, game_config: &GameConfig) -> CurrentLevel {
+        CurrentLevel {
+            handle: game_config
+                .level
+                .as_ref()
+                .map(|path| asset_server.load(path.as_str())),
+            objective: None,
+        }
+    }
+}
+
+// The level being played, copied out of its asset once it has loaded.
+#[derive(Resource, Default)]
+pub struct ActiveLevel {
+    pub level: Level,
+    // Window size over `arena_size`.
+    pub scale: Vec2,
+}
+
+impl ActiveLevel {
+    pub fn to_world(&self, position: (f32, f32)) -> Vec2 {
+        Vec2::new(position.0, position.1) * self.scale
+    }
+
+    // The middle of the arena unless the level says otherwise.
+    pub fn player_start(&self) -> Vec2 {
+        let (width, height) = self.level.arena_size;
+        self.to_world(
+            self.level
+                .player_start
+                .unwrap_or((width / 2.0, height / 2.0)),
+        )
+    }
+
+    // The arena's size in world units, i.e. the window size.
+    pub fn world_size(&self) -> Vec2 {
+        self.to_world(self.level.arena_size)
+    }
+
+    pub fn random_position(&self, rng: &mut impl Rng) -> Vec2 {
+        let (width, height) = self.level.arena_size;
+        self.to_world((rng.gen::<f32>() * width, rng.gen::<f32>() * height))
+    }
+
+    pub fn random_star_position(&self, rng: &mut impl Rng) -> Vec2 {
+        let Ok(region) = self.level.star_regions.choose_weighted(rng, |region| {
+            (region.max.0 - region.min.0) * (region.max.1 - region.min.1)
+        }) else {
+            return self.random_position(rng);
+        };
+
+        self.to_world((
+            rng.gen_range(region.min.0..=region.max.0),
+            rng.gen_range(region.min.1..=region.max.1),
+        ))
+    }
+}
+
+// Progress towards the level's win condition in the current run.
+#[derive(Resource, Default)]
+pub struct LevelRun {
+    pub started: bool,
+    pub completed: bool,
+    pub elapsed: f32,
+    pub stars: u32,
+}
@@ -0,0 +1,98 @@
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::loader::{Level, WinCondition};
+use super::resources::*;
+use crate::config::GameConfig;
+use crate::events::{GameOver, LevelCompleted, LevelStarted, StarCollected};
+use crate::game::score::resources::Score;
+
+pub fn load_level(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+) {
+    commands.insert_resource(CurrentLevel::from_config(&asset_server, &game_config));
+}
+
+pub fn reset_level_run(mut level_run: ResMut<LevelRun>) {
+    *level_run = LevelRun::default();
+}
+
+pub fn start_level(
+    current_level: Res<CurrentLevel>,
+    levels: Res<Assets<Level>>,
+    asset_server: Res<AssetServer>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut active_level: ResMut<ActiveLevel>,
+    mut level_run: ResMut<LevelRun>,
+    mut level_started_event_writer: EventWriter<LevelStarted>,
+) {
+    if level_run.started {
+        return;
+    }
+
+    let mut level = match &current_level.handle {
+        // Levels from the editor are added directly and never go through the
+        // asset server, so check for the asset itself first.
+        Some(handle) => match levels.get(handle) {
+            Some(level) => level.clone(),
+            None => match asset_server.get_load_state(handle) {
+                LoadState::Failed | LoadState::Unloaded => {
+                    println!("Falling back to the default arena");
+                    Level::default()
+                }
+                _ => return,
+            },
+        },
+        None => Level::default(),
+    };
+
+    if current_level.objective.is_some() {
+        level.win_condition = current_level.objective;
+    }
+
+    let window = window_query.get_single().unwrap();
+    let (width, height) = level.arena_size;
+    active_level.scale = Vec2::new(window.width() / width, window.height() / height);
+    println!("Starting level: {}", level.name);
+    active_level.level = level;
+
+    level_run.started = true;
+    level_started_event_writer.send(LevelStarted {});
+}
+
+pub fn track_level_run(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut level_run: ResMut<LevelRun>,
+    time: Res<Time>,
+) {
+    level_run.elapsed += time.delta_seconds();
+    level_run.stars += star_collected_event_reader.iter().count() as u32;
+}
+
+pub fn check_win_condition(
+    active_level: Res<ActiveLevel>,
+    mut level_run: ResMut<LevelRun>,
+    score: Res<Score>,
+    mut level_completed_event_writer: EventWriter<LevelCompleted>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+) {
+    if !level_run.started || level_run.completed {
+        return;
+    }
+
+    let won = match active_level.level.win_condition {
+        Some(WinCondition::Score(target)) => score.value >= target,
+        Some(WinCondition::SurviveSeconds(target)) => level_run.elapsed >= target,
+        Some(WinCondition::CollectStars(target)) => level_run.stars >= target,
+        None => false,
+    };
+    if won {
+        level_run.completed = true;
+        println!("Level complete: {}", active_level.level.name);
+        level_completed_event_writer.send(LevelCompleted { score: score.value });
+        game_over_event_writer.send(GameOver { score: score.value });
+    }
+}
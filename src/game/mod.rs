@@ -1,16 +1,46 @@
+pub mod arena;
+pub mod collision;
+pub mod daily;
 pub mod enemy;
-mod player;
+pub mod forces;
+mod hud;
+pub mod level;
+pub mod mode;
+pub mod modifiers;
+pub mod network;
+pub mod particles;
+pub mod player;
+pub mod resources;
 pub mod score;
+pub mod spawner;
 pub mod star;
 mod systems;
 
+use arena::ArenaPlugin;
+use collision::CollisionPlugin;
+use daily::DailyChallengePlugin;
 use enemy::EnemyPlugin;
+use forces::ForcesPlugin;
+use hud::HudPlugin;
+use level::LevelPlugin;
+use mode::GameModePlugin;
+use modifiers::ModifiersPlugin;
+use network::NetworkPlugin;
+use particles::ParticlesPlugin;
 use player::PlayerPlugin;
+use resources::GameRng;
 use score::ScorePlugin;
+use spawner::SpawnerPlugin;
 use star::StarPlugin;
 use systems::*;
 
-use crate::{events::GameOver, AppState};
+use crate::{
+    events::{
+        ComboReached, EnemyBounced, EnemySpawned, GameOver, LevelCompleted, LevelStarted, NearMiss,
+        PlayerHit, SpawnRequest, StarCollected, WaveStarted,
+    },
+    AppState,
+};
 
 use bevy::prelude::*;
 
@@ -19,12 +49,34 @@ pub struct GamePlugin;
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.add_state::<SimulationState>()
+            .init_resource::<GameRng>()
             .add_event::<GameOver>()
+            .add_event::<EnemySpawned>()
+            .add_event::<SpawnRequest>()
+            .add_event::<EnemyBounced>()
+            .add_event::<StarCollected>()
+            .add_event::<PlayerHit>()
+            .add_event::<ComboReached>()
+            .add_event::<NearMiss>()
+            .add_event::<WaveStarted>()
+            .add_event::<LevelStarted>()
+            .add_event::<LevelCompleted>()
             .add_system(pause_simulation.in_schedule(OnEnter(AppState::Game)))
+            .add_plugin(LevelPlugin)
+            .add_plugin(GameModePlugin)
+            .add_plugin(ModifiersPlugin)
+            .add_plugin(DailyChallengePlugin)
+            .add_plugin(ArenaPlugin)
+            .add_plugin(ForcesPlugin)
+            .add_plugin(CollisionPlugin)
+            .add_plugin(SpawnerPlugin)
             .add_plugin(EnemyPlugin)
             .add_plugin(PlayerPlugin)
             .add_plugin(ScorePlugin)
             .add_plugin(StarPlugin)
+            .add_plugin(NetworkPlugin)
+            .add_plugin(ParticlesPlugin)
+            .add_plugin(HudPlugin)
             .add_system(toggle_simulation.run_if(in_state(AppState::Game)))
             .add_system(resume_simulation.in_schedule(OnExit(AppState::Game)));
     }
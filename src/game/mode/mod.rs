@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+use crate::AppState;
+
+use super::network::is_authority;
+use super::SimulationState;
+
+pub const TIME_ATTACK_SECONDS: f32 = 60.0;
+
+pub struct GameModePlugin;
+
+impl Plugin for GameModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameMode>()
+            .init_resource::<ModeRun>()
+            .add_system(reset_mode_run.in_schedule(OnEnter(AppState::Game)))
+            .add_systems(
+                (
+                    end_run_on_player_hit,
+                    end_run_on_time_limit,
+                    score_time_alive.run_if(is_survival),
+                )
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
+            );
+    }
+}
+
+pub fn mode_has_enemies(game_mode: Res<GameMode>) -> bool {
+    game_mode.has_enemies()
+}
+
+pub fn mode_has_stars(game_mode: Res<GameMode>) -> bool {
+    game_mode.has_stars()
+}
+
+pub fn is_survival(game_mode: Res<GameMode>) -> bool {
+    *game_mode == GameMode::Survival
+}
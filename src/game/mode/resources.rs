@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::TIME_ATTACK_SECONDS;
+
+// How a run plays and, above all, how it ends.
+#[derive(
+    Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default,
+)]
+pub enum GameMode {
+    // Collect stars until an enemy gets you.
+    #[default]
+    Endless,
+    // Collect as many stars as possible before the clock runs out.
+    TimeAttack,
+    // No stars; the score counts the seconds alive.
+    Survival,
+    // No enemies and no end, just stars.
+    Zen,
+    // Endless with the day's seed and modifiers.
+    DailyChallenge,
+}
+
+impl GameMode {
+    // The modes offered on the level select screen; the daily challenge has
+    // its own entry in the main menu.
+    pub const FREE_PLAY: [GameMode; 4] = [
+        GameMode::Endless,
+        GameMode::TimeAttack,
+        GameMode::Survival,
+        GameMode::Zen,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::Endless => "Endless",
+            GameMode::TimeAttack => "Time Attack",
+            GameMode::Survival => "Survival",
+            GameMode::Zen => "Zen",
+            GameMode::DailyChallenge => "Daily Challenge",
+        }
+    }
+
+    pub fn has_enemies(&self) -> bool {
+        *self != GameMode::Zen
+    }
+
+    pub fn has_stars(&self) -> bool {
+        *self != GameMode::Survival
+    }
+
+    pub fn time_limit(&self) -> Option<f32> {
+        match self {
+            GameMode::TimeAttack => Some(TIME_ATTACK_SECONDS),
+            _ => None,
+        }
+    }
+}
+
+// Set once the mode has sent its `GameOver`, so it is only sent once.
+#[derive(Resource, Default)]
+pub struct ModeRun {
+    pub ended: bool,
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use super::resources::{GameMode, ModeRun};
+use crate::events::{GameOver, PlayerHit};
+use crate::game::level::resources::LevelRun;
+use crate::game::modifiers::resources::RunModifiers;
+use crate::game::network::LOCAL_PLAYER_ID;
+use crate::game::score::resources::Score;
+
+pub fn reset_mode_run(mut mode_run: ResMut<ModeRun>) {
+    *mode_run = ModeRun::default();
+}
+
+// Every mode with enemies ends when the local player is caught.
+pub fn end_run_on_player_hit(
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+    mut mode_run: ResMut<ModeRun>,
+    level_run: Res<LevelRun>,
+    score: Res<Score>,
+) {
+    let local_player_hit = player_hit_event_reader
+        .iter()
+        .any(|event| event.player_id == LOCAL_PLAYER_ID);
+    if local_player_hit && !mode_run.ended && !level_run.completed {
+        mode_run.ended = true;
+        game_over_event_writer.send(GameOver { score: score.value });
+    }
+}
+
+pub fn end_run_on_time_limit(
+    mut game_over_event_writer: EventWriter<GameOver>,
+    mut mode_run: ResMut<ModeRun>,
+    game_mode: Res<GameMode>,
+    level_run: Res<LevelRun>,
+    score: Res<Score>,
+) {
+    let Some(time_limit) = game_mode.time_limit() else {
+        return;
+    };
+    if level_run.elapsed >= time_limit && !mode_run.ended && !level_run.completed {
+        mode_run.ended = true;
+        println!("Time's up!");
+        game_over_event_writer.send(GameOver { score: score.value });
+    }
+}
+
+pub fn score_time_alive(
+    mut score: ResMut<Score>,
+    level_run: Res<LevelRun>,
+    mode_run: Res<ModeRun>,
+    run_modifiers: Res<RunModifiers>,
+) {
+    let points = (level_run.elapsed.floor() * run_modifiers.score_multiplier()) as u32;
+    if !mode_run.ended && score.value != points {
+        score.value = points;
+    }
+}
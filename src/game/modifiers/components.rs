@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+// The darkness over the arena with `Modifier::FogOfWar`, centred on the player.
+#[derive(Component)]
+pub struct Fog {}
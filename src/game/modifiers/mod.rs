@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+use crate::events::LevelStarted;
+use crate::AppState;
+
+use super::network::is_authority;
+use super::player::ConfinementSystemSet;
+use super::SimulationState;
+
+pub const ENEMY_SPEED_MULTIPLIER: f32 = 2.0; // With `Modifier::DoubleEnemySpeed`.
+pub const TINY_STAR_SCALE: f32 = 0.5; // With `Modifier::TinyStars`.
+pub const BIG_HEAD_SCALE: f32 = 1.75; // With `Modifier::BigHead`.
+pub const LOW_GRAVITY: f32 = 120.0; // Downward pull on enemies with `Modifier::LowGravityEnemies`.
+pub const LOW_GRAVITY_SPEED_MULTIPLIER: f32 = 0.6;
+pub const FOG_RADIUS: f32 = 200.0; // Clear area around the player with `Modifier::FogOfWar`.
+pub const FOG_FADE: f32 = 120.0; // Distance over which the fog thickens past `FOG_RADIUS`.
+pub const FOG_TEXTURE_SIZE: u32 = 256;
+pub const FOG_Z: f32 = 10.0;
+pub const FLEE_RADIUS: f32 = 180.0; // Stars closer than this to the player run away.
+pub const FLEE_SPEED: f32 = 160.0;
+
+pub struct ModifiersPlugin;
+
+impl Plugin for ModifiersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunModifiers>()
+            .add_systems(
+                (
+                    shrink_new_stars.run_if(modifier_active(Modifier::TinyStars)),
+                    enlarge_new_players.run_if(modifier_active(Modifier::BigHead)),
+                    follow_player_with_fog.run_if(modifier_active(Modifier::FogOfWar)),
+                )
+                    .in_set(OnUpdate(AppState::Game)),
+            )
+            .add_system(
+                spawn_fog
+                    .in_set(OnUpdate(AppState::Game))
+                    .run_if(modifier_active(Modifier::FogOfWar))
+                    .run_if(on_event::<LevelStarted>()),
+            )
+            .add_system(
+                wrap_around_arena
+                    .in_set(ConfinementSystemSet)
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .run_if(is_authority)
+                    .run_if(not(arena_has_walls)),
+            )
+            .add_systems(
+                (
+                    drift_enemies_downward.run_if(modifier_active(Modifier::LowGravityEnemies)),
+                    flee_from_player.run_if(modifier_active(Modifier::FleeingStars)),
+                )
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
+            )
+            .add_system(despawn_fog.in_schedule(OnExit(AppState::Game)))
+            // Kept until the main menu so restarting from game over replays
+            // the same mutators.
+            .add_system(clear_run_modifiers.in_schedule(OnEnter(AppState::MainMenu)));
+    }
+}
+
+pub fn arena_has_walls(run_modifiers: Res<RunModifiers>) -> bool {
+    !run_modifiers.has(Modifier::NoWalls)
+}
+
+pub fn modifier_active(modifier: Modifier) -> impl FnMut(Res<RunModifiers>) -> bool + Clone {
+    move |run_modifiers: Res<RunModifiers>| run_modifiers.has(modifier)
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    BIG_HEAD_SCALE, ENEMY_SPEED_MULTIPLIER, LOW_GRAVITY_SPEED_MULTIPLIER, TINY_STAR_SCALE,
+};
+
+// A tweak to the rules that lasts for one run. Any combination can be picked
+// before a free-play run, each scaling the score it earns.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modifier {
+    DoubleEnemySpeed,
+    TinyStars,
+    // The arena edges wrap around instead of stopping anything.
+    NoWalls,
+    BigHead,
+    // Slower enemies that sink in arcs.
+    LowGravityEnemies,
+    // Left and right are swapped.
+    MirroredControls,
+    // Only the area around the player can be seen.
+    FogOfWar,
+    FleeingStars,
+}
+
+impl Modifier {
+    pub const ALL: [Modifier; 8] = [
+        Modifier::DoubleEnemySpeed,
+        Modifier::TinyStars,
+        Modifier::NoWalls,
+        Modifier::BigHead,
+        Modifier::LowGravityEnemies,
+        Modifier::MirroredControls,
+        Modifier::FogOfWar,
+        Modifier::FleeingStars,
+    ];
+
+    // The pool the daily challenge picks from.
+    pub const DAILY: [Modifier; 3] = [
+        Modifier::DoubleEnemySpeed,
+        Modifier::TinyStars,
+        Modifier::NoWalls,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Modifier::DoubleEnemySpeed => "Double Enemy Speed",
+            Modifier::TinyStars => "Tiny Stars",
+            Modifier::NoWalls => "No Walls",
+            Modifier::BigHead => "Big Head",
+            Modifier::LowGravityEnemies => "Low Gravity Enemies",
+            Modifier::MirroredControls => "Mirrored Controls",
+            Modifier::FogOfWar => "Fog of War",
+            Modifier::FleeingStars => "Fleeing Stars",
+        }
+    }
+
+    // Harder modifiers are worth more; slower enemies are worth less.
+    pub fn score_multiplier(&self) -> f32 {
+        match self {
+            Modifier::DoubleEnemySpeed => 1.5,
+            Modifier::TinyStars => 1.25,
+            Modifier::NoWalls => 1.1,
+            Modifier::BigHead => 1.25,
+            Modifier::LowGravityEnemies => 0.9,
+            Modifier::MirroredControls => 1.5,
+            Modifier::FogOfWar => 1.5,
+            Modifier::FleeingStars => 1.25,
+        }
+    }
+}
+
+// The modifiers in play for the current run; cleared back at the main menu.
+#[derive(Resource, Default, Debug)]
+pub struct RunModifiers {
+    pub active: Vec<Modifier>,
+}
+
+impl RunModifiers {
+    pub fn has(&self, modifier: Modifier) -> bool {
+        self.active.contains(&modifier)
+    }
+
+    // The multipliers stack.
+    pub fn score_multiplier(&self) -> f32 {
+        self.active.iter().map(Modifier::score_multiplier).product()
+    }
+
+    pub fn enemy_speed_multiplier(&self) -> f32 {
+        let mut multiplier = 1.0;
+        if self.has(Modifier::DoubleEnemySpeed) {
+            multiplier *= ENEMY_SPEED_MULTIPLIER;
+        }
+        if self.has(Modifier::LowGravityEnemies) {
+            multiplier *= LOW_GRAVITY_SPEED_MULTIPLIER;
+        }
+        multiplier
+    }
+
+    pub fn star_scale(&self) -> f32 {
+        if self.has(Modifier::TinyStars) {
+            TINY_STAR_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    pub fn player_scale(&self) -> f32 {
+        if self.has(Modifier::BigHead) {
+            BIG_HEAD_SCALE
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_modifiers_score_normally() {
+        assert_eq!(RunModifiers::default().score_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn multipliers_stack() {
+        let run_modifiers = RunModifiers {
+            active: vec![Modifier::DoubleEnemySpeed, Modifier::TinyStars],
+        };
+        assert!((run_modifiers.score_multiplier() - 1.875).abs() < 1e-5);
+    }
+
+    #[test]
+    fn easier_modifiers_lower_the_score() {
+        let run_modifiers = RunModifiers {
+            active: vec![Modifier::LowGravityEnemies],
+        };
+        assert!(run_modifiers.score_multiplier() < 1.0);
+    }
+}
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use super::components::Fog;
+use super::resources::RunModifiers;
+use super::{FLEE_RADIUS, FLEE_SPEED, FOG_FADE, FOG_RADIUS, FOG_TEXTURE_SIZE, FOG_Z, LOW_GRAVITY};
+use crate::game::collision::components::PreviousPosition;
+use crate::game::enemy::components::Enemy;
+use crate::game::level::resources::ActiveLevel;
+use crate::game::player::components::Player;
+use crate::game::star::components::Star;
+use crate::game::star::STAR_SIZE;
+
+type WrappingFilter = Or<(With<Player>, With<Enemy>)>;
+type FogFilter = (With<Fog>, Without<Player>);
+type FleeingStarFilter = (With<Star>, Without<Player>);
+
+pub fn clear_run_modifiers(mut run_modifiers: ResMut<RunModifiers>) {
+    *run_modifiers = RunModifiers::default();
+}
+
+// Pickup distance follows the star's scale, so this shrinks the hitbox too.
+pub fn shrink_new_stars(
+    mut star_query: Query<&mut Transform, Added<Star>>,
+    run_modifiers: Res<RunModifiers>,
+) {
+    let scale = run_modifiers.star_scale();
+    for mut transform in star_query.iter_mut() {
+        transform.scale = Vec3::new(scale, scale, 1.0);
+    }
+}
+
+// As with stars, the player's hitbox follows its scale.
+pub fn enlarge_new_players(
+    mut player_query: Query<&mut Transform, Added<Player>>,
+    run_modifiers: Res<RunModifiers>,
+) {
+    let scale = run_modifiers.player_scale();
+    for mut transform in player_query.iter_mut() {
+        transform.scale = Vec3::new(scale, scale, 1.0);
+    }
+}
+
+// Stands in for the confinement systems when the arena has no walls.
+pub fn wrap_around_arena(
+    mut transform_query: Query<(&mut Transform, Option<&mut PreviousPosition>), WrappingFilter>,
+    active_level: Res<ActiveLevel>,
+) {
+    let size = active_level.world_size();
+    for (mut transform, previous_position) in transform_query.iter_mut() {
+        let position = transform.translation.truncate();
+        let wrapped = Vec2::new(position.x.rem_euclid(size.x), position.y.rem_euclid(size.y));
+        transform.translation = wrapped.extend(transform.translation.z);
+        // Carry the start of the frame across too, or the jump reads as a sweep
+        // across the whole arena.
+        if let Some(mut previous_position) = previous_position {
+            previous_position.value += wrapped - position;
+        }
+    }
+}
+
+// Bends each enemy's heading towards the floor; the walls still bounce them
+// back up, so they travel in arcs.
+pub fn drift_enemies_downward(
+    mut enemy_query: Query<&mut Enemy>,
+    run_modifiers: Res<RunModifiers>,
+    time: Res<Time>,
+) {
+    let speed_multiplier = run_modifiers.enemy_speed_multiplier();
+    for mut enemy in enemy_query.iter_mut() {
+        let velocity = enemy.direction * enemy.speed * speed_multiplier
+            - Vec2::Y * LOW_GRAVITY * time.delta_seconds();
+        if let Some(direction) = velocity.try_normalize() {
+            enemy.direction = direction;
+        }
+    }
+}
+
+pub fn flee_from_player(
+    mut star_query: Query<&mut Transform, FleeingStarFilter>,
+    player_query: Query<&Transform, With<Player>>,
+    active_level: Res<ActiveLevel>,
+    time: Res<Time>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_position = player_transform.translation.truncate();
+    let half_star_size = STAR_SIZE / 2.0;
+    let max = active_level.world_size() - half_star_size;
+
+    for mut transform in star_query.iter_mut() {
+        let away = transform.translation.truncate() - player_position;
+        if away.length() >= FLEE_RADIUS {
+            continue;
+        }
+        let step = away.normalize_or_zero() * FLEE_SPEED * time.delta_seconds();
+        let position =
+            (transform.translation.truncate() + step).clamp(Vec2::splat(half_star_size), max);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+// One large sprite, clear in the middle and black towards the edges, big
+// enough to cover the arena from any corner.
+pub fn spawn_fog(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    active_level: Res<ActiveLevel>,
+) {
+    let fog_size = active_level.world_size().length() * 2.0;
+    let texture = images.add(build_fog_texture(fog_size));
+    let position = active_level.player_start();
+
+    commands.spawn((
+        SpriteBundle {
+            transform: Transform::from_xyz(position.x, position.y, FOG_Z),
+            texture,
+            sprite: Sprite {
+                custom_size: Some(Vec2::splat(fog_size)),
+                ..default()
+            },
+            ..default()
+        },
+        Fog {},
+    ));
+}
+
+fn build_fog_texture(fog_size: f32) -> Image {
+    let centre = FOG_TEXTURE_SIZE as f32 / 2.0;
+    let world_per_pixel = fog_size / FOG_TEXTURE_SIZE as f32;
+    let mut data = Vec::with_capacity((FOG_TEXTURE_SIZE * FOG_TEXTURE_SIZE * 4) as usize);
+    for y in 0..FOG_TEXTURE_SIZE {
+        for x in 0..FOG_TEXTURE_SIZE {
+            let pixel = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let distance = pixel.distance(Vec2::splat(centre)) * world_per_pixel;
+            let alpha = ((distance - FOG_RADIUS) / FOG_FADE).clamp(0.0, 1.0);
+            data.extend_from_slice(&[0, 0, 0, (alpha * 255.0) as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: FOG_TEXTURE_SIZE,
+            height: FOG_TEXTURE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+pub fn follow_player_with_fog(
+    mut fog_query: Query<&mut Transform, FogFilter>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    if let (Ok(mut fog_transform), Ok(player_transform)) =
+        (fog_query.get_single_mut(), player_query.get_single())
+    {
+        fog_transform.translation.x = player_transform.translation.x;
+        fog_transform.translation.y = player_transform.translation.y;
+    }
+}
+
+pub fn despawn_fog(mut commands: Commands, fog_query: Query<Entity, With<Fog>>) {
+    for fog_entity in fog_query.iter() {
+        commands.entity(fog_entity).despawn();
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+// Stable id shared between the host and every client for one replicated entity.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NetworkId(pub u32);
+
+// A client's ball on the host, driven by the inputs that client sends.
+#[derive(Component)]
+pub struct RemotePlayer {
+    pub player_id: u32,
+    pub direction: Vec2,
+    pub since_last_input: f32,
+}
+
+// On a client, every replicated entity moves from `start` to `end` over one
+// snapshot interval instead of jumping.
+#[derive(Component)]
+pub struct Interpolation {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub elapsed: f32,
+}
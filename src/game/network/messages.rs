@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::enemy::components::EnemyKind;
+use crate::game::star::components::StarKind;
+
+// Everything that goes over the wire. Positions are plain arrays so we don't
+// need bevy's `serialize` feature just for `Vec2`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum NetMessage {
+    // Client -> host, sent every frame. Also acts as the join message.
+    Input { player_id: u32, direction: [f32; 2] },
+    // Host -> clients, sent at `SNAPSHOT_RATE`.
+    Snapshot(Snapshot),
+    // Host -> clients, so they leave the arena along with the host.
+    GameOver { score: u32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Snapshot {
+    pub tick: u32,
+    // A snapshot too big for one packet goes out in `parts` packets with the
+    // same tick, each carrying some of the entities.
+    pub part: u32,
+    pub parts: u32,
+    pub score: u32,
+    pub entities: Vec<EntityState>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EntityState {
+    pub id: u32,
+    pub kind: EntityKind,
+    pub position: [f32; 2],
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityKind {
+    Player { player_id: u32 },
+    Enemy { kind: EnemyKind },
+    Star { kind: StarKind },
+}
+
+impl Snapshot {
+    // Splits the entities evenly over as many parts as it takes to keep each
+    // encoded part under `max_size` bytes.
+    pub fn split(self, max_size: usize) -> Vec<Snapshot> {
+        let size = NetMessage::Snapshot(self.clone())
+            .encode()
+            .map_or(0, |bytes| bytes.len());
+        if size <= max_size {
+            return vec![self];
+        }
+
+        let chunk_size = self.entities.len() / (size / max_size + 1) + 1;
+        let parts = self.entities.chunks(chunk_size).len() as u32;
+        self.entities
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(part, entities)| Snapshot {
+                tick: self.tick,
+                part: part as u32,
+                parts,
+                score: self.score,
+                entities: entities.to_vec(),
+            })
+            .collect()
+    }
+}
+
+impl NetMessage {
+    pub fn encode(&self) -> Option<Vec<u8>> {
+        ron::to_string(self).ok().map(String::into_bytes)
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<NetMessage> {
+        let text = std::str::from_utf8(bytes).ok()?;
+        ron::from_str(text).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::network::MAX_PACKET_SIZE;
+
+    fn snapshot(entity_count: u32) -> Snapshot {
+        Snapshot {
+            tick: 9,
+            part: 0,
+            parts: 1,
+            score: 42,
+            entities: (0..entity_count)
+                .map(|id| EntityState {
+                    id,
+                    kind: EntityKind::Enemy {
+                        kind: EnemyKind::Bouncer,
+                    },
+                    position: [id as f32 * 10.5, 300.25],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn small_snapshots_go_out_whole() {
+        let parts = snapshot(3).split(MAX_PACKET_SIZE);
+
+        assert_eq!(parts.len(), 1);
+        assert_eq!((parts[0].part, parts[0].parts), (0, 1));
+        assert_eq!(parts[0].entities.len(), 3);
+    }
+
+    #[test]
+    fn large_snapshots_split_into_parts_that_fit() {
+        let parts = snapshot(5000).split(MAX_PACKET_SIZE);
+
+        assert!(parts.len() > 1);
+        for (index, part) in parts.iter().enumerate() {
+            assert_eq!((part.tick, part.score), (9, 42));
+            assert_eq!(part.part, index as u32);
+            assert_eq!(part.parts, parts.len() as u32);
+            let size = NetMessage::Snapshot(part.clone()).encode().unwrap().len();
+            assert!(size <= MAX_PACKET_SIZE, "part {} is {} bytes", index, size);
+        }
+
+        let ids: Vec<u32> = parts
+            .iter()
+            .flat_map(|part| part.entities.iter().map(|state| state.id))
+            .collect();
+        assert_eq!(ids, (0..5000).collect::<Vec<u32>>());
+    }
+}
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod messages;
+pub mod relay;
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+use crate::AppState;
+
+use super::player::{ConfinementSystemSet, MovementSystemSet};
+use super::SimulationState;
+
+pub const DEFAULT_RELAY_ADDRESS: &str = "0.0.0.0:7777";
+pub const SNAPSHOT_RATE: f32 = 20.0; // Snapshots per second sent by the host.
+pub const PEER_TIMEOUT: f32 = 5.0; // Seconds without packets before a peer is dropped.
+pub const MAX_PACKET_SIZE: usize = 65_507;
+pub const LOCAL_PLAYER_ID: u32 = 0; // The host's own player; clients pick a random non-zero id.
+pub const OTHER_PLAYER_COLOUR: Color = Color::rgb(0.6, 1.0, 0.6); // Tints everyone else's ball.
+
+pub struct NetworkPlugin;
+
+impl Plugin for NetworkPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NetworkRole>()
+            .init_resource::<SnapshotTimer>()
+            .init_resource::<NetworkIdCounter>()
+            .init_resource::<ReceivedSnapshots>()
+            .add_startup_system(setup_host.run_if(is_host))
+            .add_startup_system(setup_client.run_if(is_client))
+            // Host
+            .add_systems(
+                (
+                    assign_network_ids,
+                    receive_inputs,
+                    send_snapshots,
+                    send_game_over,
+                )
+                    .in_set(OnUpdate(AppState::Game))
+                    .distributive_run_if(is_host),
+            )
+            .add_systems(
+                (
+                    remote_player_movement.in_set(MovementSystemSet),
+                    enemy_hit_remote_player.after(ConfinementSystemSet),
+                    remote_player_hit_star,
+                )
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_host),
+            )
+            .add_system(
+                despawn_remote_players
+                    .run_if(is_host)
+                    .in_schedule(OnExit(AppState::Game)),
+            )
+            // Client
+            .add_systems(
+                (
+                    send_input,
+                    receive_host_messages,
+                    apply_snapshot.after(receive_host_messages),
+                    interpolate_replicated_entities.after(apply_snapshot),
+                )
+                    .in_set(OnUpdate(AppState::Game))
+                    .distributive_run_if(is_client),
+            )
+            .add_system(
+                despawn_replicated_entities
+                    .run_if(is_client)
+                    .in_schedule(OnExit(AppState::Game)),
+            );
+    }
+}
+
+// Offline play and the host run the real simulation; clients only mirror it.
+pub fn is_authority(network_role: Res<NetworkRole>) -> bool {
+    network_role.is_authority()
+}
+
+pub fn is_host(network_role: Res<NetworkRole>) -> bool {
+    matches!(*network_role, NetworkRole::Host { .. })
+}
+
+pub fn is_client(network_role: Res<NetworkRole>) -> bool {
+    matches!(*network_role, NetworkRole::Client { .. })
+}
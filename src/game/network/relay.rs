@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use super::messages::NetMessage;
+use super::{MAX_PACKET_SIZE, PEER_TIMEOUT};
+
+// The relay is a dumb forwarder so that host and clients only ever need to know
+// one address. Snapshots and game overs come from the host and go to every
+// client, inputs come from a client and go to the host.
+pub fn run_relay(socket: UdpSocket) {
+    let peer_timeout = Duration::from_secs_f32(PEER_TIMEOUT);
+    let mut host: Option<(SocketAddr, Instant)> = None;
+    let mut clients: HashMap<SocketAddr, Instant> = HashMap::new();
+    let mut buffer = [0u8; MAX_PACKET_SIZE];
+
+    if let Ok(address) = socket.local_addr() {
+        println!("Relay listening on {}", address);
+    }
+
+    loop {
+        let (length, sender) = match socket.recv_from(&mut buffer) {
+            Ok(received) => received,
+            // A read timeout just means nobody has said anything for a while.
+            Err(error) if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                continue;
+            }
+            Err(error) => {
+                println!("Relay receive error: {}", error);
+                continue;
+            }
+        };
+        let packet = &buffer[..length];
+
+        match NetMessage::decode(packet) {
+            Some(NetMessage::Snapshot(_) | NetMessage::GameOver { .. }) => {
+                // Whoever sends first is the host. Host messages from anyone
+                // else are dropped until the host has gone quiet.
+                match host {
+                    Some((address, _)) if address == sender => {}
+                    Some((_, last_seen)) if last_seen.elapsed() < peer_timeout => {
+                        println!("Relay: dropped host message from {}", sender);
+                        continue;
+                    }
+                    _ => println!("Relay: host is {}", sender),
+                }
+                host = Some((sender, Instant::now()));
+
+                clients.retain(|_, last_seen| last_seen.elapsed() < peer_timeout);
+                for client in clients.keys() {
+                    let _ = socket.send_to(packet, client);
+                }
+            }
+            Some(NetMessage::Input { .. }) => {
+                if clients.insert(sender, Instant::now()).is_none() {
+                    println!("Relay: client joined from {}", sender);
+                }
+                if let Some((host, _)) = host {
+                    let _ = socket.send_to(packet, host);
+                }
+            }
+            None => println!("Relay: dropped malformed packet from {}", sender),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, UdpSocket};
+    use std::time::Duration;
+
+    use super::run_relay;
+    use crate::game::network::messages::{NetMessage, Snapshot};
+    use crate::game::network::MAX_PACKET_SIZE;
+
+    fn bind() -> UdpSocket {
+        let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_millis(200)))
+            .unwrap();
+        socket
+    }
+
+    fn send(socket: &UdpSocket, relay: &UdpSocket, message: &NetMessage) {
+        let bytes = message.encode().unwrap();
+        socket.send_to(&bytes, relay.local_addr().unwrap()).unwrap();
+    }
+
+    fn receive(socket: &UdpSocket) -> Option<NetMessage> {
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        let (length, _) = socket.recv_from(&mut buffer).ok()?;
+        NetMessage::decode(&buffer[..length])
+    }
+
+    fn snapshot(tick: u32) -> NetMessage {
+        NetMessage::Snapshot(Snapshot {
+            tick,
+            part: 0,
+            parts: 1,
+            score: 0,
+            entities: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn relay_round_trip() {
+        let relay = bind();
+        let relay_socket = relay.try_clone().unwrap();
+        std::thread::spawn(move || run_relay(relay_socket));
+        let host = bind();
+        let client = bind();
+        let impostor = bind();
+
+        // The first snapshot makes `host` the host.
+        send(&host, &relay, &snapshot(1));
+        send(
+            &client,
+            &relay,
+            &NetMessage::Input {
+                player_id: 7,
+                direction: [1.0, 0.0],
+            },
+        );
+        assert!(matches!(
+            receive(&host),
+            Some(NetMessage::Input { player_id: 7, .. })
+        ));
+
+        send(&host, &relay, &snapshot(2));
+        assert!(matches!(
+            receive(&client),
+            Some(NetMessage::Snapshot(Snapshot { tick: 2, .. }))
+        ));
+
+        // Nobody else gets to speak for the host.
+        send(&impostor, &relay, &snapshot(3));
+        assert!(receive(&client).is_none());
+    }
+}
@@ -0,0 +1,191 @@
+use std::net::{SocketAddr, UdpSocket};
+
+use bevy::prelude::*;
+
+use super::messages::Snapshot;
+use super::{DEFAULT_RELAY_ADDRESS, SNAPSHOT_RATE};
+
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Default)]
+pub enum NetworkRole {
+    #[default]
+    Offline,
+    // Runs the simulation and a relay on `relay_address`.
+    Host {
+        relay_address: SocketAddr,
+    },
+    // Sends inputs to the relay and renders the host's snapshots.
+    Client {
+        relay_address: SocketAddr,
+        player_id: u32,
+    },
+    // Headless forwarder, handled in `main` before the app is built.
+    Relay {
+        relay_address: SocketAddr,
+    },
+}
+
+impl NetworkRole {
+    // Usage: `--host [address]`, `--join [address]` or `--relay [address]`.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> NetworkRole {
+        while let Some(arg) = args.next() {
+            let make_role: fn(SocketAddr) -> NetworkRole = match arg.as_str() {
+                "--host" => |relay_address| NetworkRole::Host { relay_address },
+                "--join" => |relay_address| NetworkRole::Client {
+                    relay_address,
+                    player_id: rand::random::<u32>().max(1),
+                },
+                "--relay" => |relay_address| NetworkRole::Relay { relay_address },
+                _ => continue,
+            };
+            let relay_address = args
+                .next()
+                .and_then(|address| address.parse().ok())
+                .unwrap_or_else(|| DEFAULT_RELAY_ADDRESS.parse().unwrap());
+            return make_role(relay_address);
+        }
+        NetworkRole::Offline
+    }
+
+    pub fn is_authority(&self) -> bool {
+        !matches!(self, NetworkRole::Client { .. })
+    }
+}
+
+#[derive(Resource)]
+pub struct NetworkSocket {
+    pub socket: UdpSocket,
+    pub relay_address: SocketAddr,
+}
+
+#[derive(Resource)]
+pub struct SnapshotTimer {
+    pub timer: Timer,
+    pub tick: u32,
+}
+
+impl Default for SnapshotTimer {
+    fn default() -> SnapshotTimer {
+        SnapshotTimer {
+            timer: Timer::from_seconds(1.0 / SNAPSHOT_RATE, TimerMode::Repeating),
+            tick: 0,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct NetworkIdCounter {
+    pub next: u32,
+}
+
+// What a client has heard from the host: the tick it last applied, the parts of
+// a newer snapshot still coming in, and a whole one waiting to be applied.
+#[derive(Resource, Default)]
+pub struct ReceivedSnapshots {
+    pub last_tick: Option<u32>,
+    pub parts: Vec<Snapshot>,
+    pub complete: Option<Snapshot>,
+}
+
+impl ReceivedSnapshots {
+    // Older or duplicated parts are dropped and a newer tick replaces the one
+    // being collected, so only the newest snapshot is ever applied.
+    pub fn add(&mut self, snapshot: Snapshot) {
+        if let Some(last_tick) = self.last_tick {
+            if !is_newer_tick(snapshot.tick, last_tick) {
+                return;
+            }
+        }
+        if let Some(pending) = self.parts.first() {
+            if is_newer_tick(pending.tick, snapshot.tick) {
+                return;
+            }
+            if pending.tick != snapshot.tick {
+                self.parts.clear();
+            }
+        }
+        if !self.parts.iter().any(|part| part.part == snapshot.part) {
+            self.parts.push(snapshot);
+        }
+
+        if self.parts.len() >= self.parts[0].parts as usize {
+            let mut parts = std::mem::take(&mut self.parts).into_iter();
+            if let Some(mut whole) = parts.next() {
+                for part in parts {
+                    whole.entities.extend(part.entities);
+                }
+                self.last_tick = Some(whole.tick);
+                self.complete = Some(whole);
+            }
+        }
+    }
+}
+
+// Ticks wrap, so "newer" means less than half the range ahead.
+fn is_newer_tick(tick: u32, than: u32) -> bool {
+    tick.wrapping_sub(than) as i32 > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::network::messages::{EntityKind, EntityState};
+
+    fn part(tick: u32, part: u32, parts: u32) -> Snapshot {
+        Snapshot {
+            tick,
+            part,
+            parts,
+            score: 0,
+            entities: vec![EntityState {
+                id: part,
+                kind: EntityKind::Player { player_id: 0 },
+                position: [0.0, 0.0],
+            }],
+        }
+    }
+
+    #[test]
+    fn parts_are_joined_once_all_have_arrived() {
+        let mut received_snapshots = ReceivedSnapshots::default();
+
+        received_snapshots.add(part(5, 1, 3));
+        received_snapshots.add(part(5, 1, 3));
+        received_snapshots.add(part(5, 0, 3));
+        assert!(received_snapshots.complete.is_none());
+
+        received_snapshots.add(part(5, 2, 3));
+        let whole = received_snapshots.complete.take().unwrap();
+        assert_eq!(whole.tick, 5);
+        assert_eq!(whole.entities.len(), 3);
+        assert_eq!(received_snapshots.last_tick, Some(5));
+    }
+
+    #[test]
+    fn old_ticks_are_dropped() {
+        let mut received_snapshots = ReceivedSnapshots::default();
+
+        received_snapshots.add(part(5, 0, 1));
+        received_snapshots.complete = None;
+        received_snapshots.add(part(4, 0, 1));
+        received_snapshots.add(part(5, 0, 1));
+        assert!(received_snapshots.complete.is_none());
+
+        // Parts of a newer tick replace the one being collected, and stragglers
+        // from the older one are ignored.
+        received_snapshots.add(part(6, 0, 2));
+        received_snapshots.add(part(7, 0, 2));
+        received_snapshots.add(part(6, 1, 2));
+        assert!(received_snapshots.complete.is_none());
+        received_snapshots.add(part(7, 1, 2));
+        assert_eq!(received_snapshots.complete.unwrap().tick, 7);
+    }
+
+    #[test]
+    fn ticks_wrap_around() {
+        let mut received_snapshots = ReceivedSnapshots::default();
+
+        received_snapshots.add(part(u32::MAX, 0, 1));
+        received_snapshots.add(part(0, 0, 1));
+        assert_eq!(received_snapshots.last_tick, Some(0));
+    }
+}
@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::{Ipv4Addr, SocketAddr, UdpSocket};
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::components::*;
+use super::messages::*;
+use super::relay::run_relay;
+use super::resources::*;
+use super::MAX_PACKET_SIZE;
+use super::{LOCAL_PLAYER_ID, OTHER_PLAYER_COLOUR, PEER_TIMEOUT};
+use crate::assets::GameAssets;
+use crate::config::GameConfig;
+use crate::events::{GameOver, PlayerHit, StarCollected};
+use crate::game::collision::components::PreviousPosition;
+use crate::game::collision::time_of_impact;
+use crate::game::enemy::components::{Enemy, EnemyKind};
+use crate::game::enemy::ENEMY_SIZE;
+use crate::game::player::components::{Player, Velocity};
+use crate::game::player::{bounce_off_walls, keyboard_direction, steer, PLAYER_SIZE};
+use crate::game::score::resources::Score;
+use crate::game::star::components::Star;
+use crate::game::star::STAR_SIZE;
+
+// Everything the host replicates that hasn't been given an id yet.
+type UnassignedFilter = (
+    Or<(With<Player>, With<RemotePlayer>, With<Enemy>, With<Star>)>,
+    Without<NetworkId>,
+);
+
+type ReplicatedItem<'a> = (
+    &'a NetworkId,
+    &'a Transform,
+    Option<&'a RemotePlayer>,
+    Option<&'a Player>,
+    Option<&'a EnemyKind>,
+    Option<&'a Star>,
+);
+
+pub fn setup_host(mut commands: Commands, network_role: Res<NetworkRole>) {
+    if let NetworkRole::Host { relay_address } = *network_role {
+        // If something else (e.g. a standalone `--relay`) already owns the
+        // address we just talk to that instead.
+        match UdpSocket::bind(relay_address) {
+            Ok(relay_socket) => {
+                std::thread::spawn(move || run_relay(relay_socket));
+            }
+            Err(error) => println!("Could not start relay on {}: {}", relay_address, error),
+        }
+        commands.insert_resource(bind_socket(relay_address));
+    }
+}
+
+pub fn setup_client(mut commands: Commands, network_role: Res<NetworkRole>) {
+    if let NetworkRole::Client { relay_address, .. } = *network_role {
+        commands.insert_resource(bind_socket(relay_address));
+    }
+}
+
+fn bind_socket(mut relay_address: SocketAddr) -> NetworkSocket {
+    // A relay bound to 0.0.0.0 is reachable on loopback.
+    if relay_address.ip().is_unspecified() {
+        relay_address.set_ip(Ipv4Addr::LOCALHOST.into());
+    }
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).expect("Failed to bind UDP socket");
+    socket
+        .set_nonblocking(true)
+        .expect("Failed to make UDP socket non-blocking");
+
+    NetworkSocket {
+        socket,
+        relay_address,
+    }
+}
+
+fn receive_messages(network_socket: &NetworkSocket) -> Vec<NetMessage> {
+    let mut messages = Vec::new();
+    let mut buffer = [0u8; MAX_PACKET_SIZE];
+
+    loop {
+        match network_socket.socket.recv_from(&mut buffer) {
+            Ok((length, _)) => {
+                if let Some(message) = NetMessage::decode(&buffer[..length]) {
+                    messages.push(message);
+                }
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+            // Windows reports ICMP "port unreachable" as an error on the next
+            // receive; the relay just isn't up yet so keep draining.
+            Err(error) if error.kind() == ErrorKind::ConnectionReset => continue,
+            Err(error) => {
+                println!("Network receive error: {}", error);
+                break;
+            }
+        }
+    }
+
+    messages
+}
+
+fn send_message(network_socket: &NetworkSocket, message: &NetMessage) {
+    match message.encode() {
+        Some(bytes) if bytes.len() <= MAX_PACKET_SIZE => {
+            let _ = network_socket
+                .socket
+                .send_to(&bytes, network_socket.relay_address);
+        }
+        Some(bytes) => println!("Dropping {} byte packet, too large", bytes.len()),
+        None => println!("Failed to encode network message"),
+    }
+}
+
+pub fn assign_network_ids(
+    mut commands: Commands,
+    entity_query: Query<Entity, UnassignedFilter>,
+    mut network_id_counter: ResMut<NetworkIdCounter>,
+) {
+    for entity in entity_query.iter() {
+        commands
+            .entity(entity)
+            .insert(NetworkId(network_id_counter.next));
+        network_id_counter.next += 1;
+    }
+}
+
+pub fn receive_inputs(
+    mut commands: Commands,
+    network_socket: Res<NetworkSocket>,
+    mut remote_player_query: Query<&mut RemotePlayer>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    game_assets: Res<GameAssets>,
+) {
+    for message in receive_messages(&network_socket) {
+        if let NetMessage::Input {
+            player_id,
+            direction,
+        } = message
+        {
+            let direction = Vec2::from(direction).clamp_length_max(1.0);
+
+            if let Some(mut remote_player) = remote_player_query
+                .iter_mut()
+                .find(|remote_player| remote_player.player_id == player_id)
+            {
+                remote_player.direction = direction;
+                remote_player.since_last_input = 0.0;
+            } else {
+                println!("Player {} joined!", player_id);
+                let window = window_query.get_single().unwrap();
+                let position = Vec2::new(window.width() / 2.0, window.height() / 2.0);
+
+                commands.spawn((
+                    SpriteBundle {
+                        transform: Transform::from_translation(position.extend(0.0)),
+                        texture: game_assets.player.clone(),
+                        sprite: Sprite {
+                            color: OTHER_PLAYER_COLOUR,
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    RemotePlayer {
+                        player_id,
+                        direction,
+                        since_last_input: 0.0,
+                    },
+                    Velocity::default(),
+                    PreviousPosition { value: position },
+                ));
+            }
+        }
+    }
+}
+
+pub fn remote_player_movement(
+    mut commands: Commands,
+    mut remote_player_query: Query<(Entity, &mut Transform, &mut Velocity, &mut RemotePlayer)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    game_config: Res<GameConfig>,
+    time: Res<Time>,
+) {
+    let window = window_query.get_single().unwrap();
+
+    let half_player_size = PLAYER_SIZE / 2.0;
+    let min = Vec2::splat(half_player_size);
+    let max = Vec2::new(window.width(), window.height()) - half_player_size;
+
+    for (entity, mut transform, mut velocity, mut remote_player) in remote_player_query.iter_mut() {
+        remote_player.since_last_input += time.delta_seconds();
+        if remote_player.since_last_input > PEER_TIMEOUT {
+            println!("Player {} timed out!", remote_player.player_id);
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        velocity.value = steer(
+            velocity.value,
+            remote_player.direction,
+            &game_config.player,
+            time.delta_seconds(),
+        );
+        let mut position = transform.translation.truncate() + velocity.value * time.delta_seconds();
+        bounce_off_walls(
+            &mut position,
+            &mut velocity.value,
+            min,
+            max,
+            game_config.player.wall_bounciness,
+        );
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
+
+pub fn enemy_hit_remote_player(
+    mut commands: Commands,
+    remote_player_query: Query<(Entity, &Transform, &PreviousPosition, &RemotePlayer)>,
+    enemy_query: Query<(Entity, &Transform, &PreviousPosition, &EnemyKind), With<Enemy>>,
+    mut player_hit_event_writer: EventWriter<PlayerHit>,
+) {
+    for (remote_player_entity, remote_player_transform, remote_player_previous, remote_player) in
+        remote_player_query.iter()
+    {
+        let remote_player_motion =
+            remote_player_transform.translation.truncate() - remote_player_previous.value;
+        for (enemy_entity, enemy_transform, enemy_previous, enemy_kind) in enemy_query.iter() {
+            let enemy_motion = enemy_transform.translation.truncate() - enemy_previous.value;
+            if time_of_impact(
+                enemy_previous.value - remote_player_previous.value,
+                enemy_motion - remote_player_motion,
+                PLAYER_SIZE / 2.0 + ENEMY_SIZE / 2.0,
+            )
+            .is_some()
+            {
+                player_hit_event_writer.send(PlayerHit {
+                    player_id: remote_player.player_id,
+                    enemy: enemy_entity,
+                    enemy_kind: *enemy_kind,
+                    position: remote_player_transform.translation.truncate(),
+                });
+                // The ball comes back with that client's next input, at the centre.
+                commands.entity(remote_player_entity).despawn();
+                break;
+            }
+        }
+    }
+}
+
+pub fn remote_player_hit_star(
+    mut commands: Commands,
+    remote_player_query: Query<(&Transform, &RemotePlayer)>,
+    star_query: Query<(Entity, &Transform, &Star)>,
+    game_config: Res<GameConfig>,
+    mut star_collected_event_writer: EventWriter<StarCollected>,
+) {
+    for (star_entity, star_transform, star) in star_query.iter() {
+        let collector = remote_player_query
+            .iter()
+            .find(|(remote_player_transform, _)| {
+                remote_player_transform
+                    .translation
+                    .distance(star_transform.translation)
+                    < PLAYER_SIZE / 2.0 + STAR_SIZE * star_transform.scale.x / 2.0
+            });
+        if let Some((_, remote_player)) = collector {
+            star_collected_event_writer.send(StarCollected {
+                player_id: remote_player.player_id,
+                position: star_transform.translation.truncate(),
+                value: star.value(&game_config.stars),
+            });
+            commands.entity(star_entity).despawn();
+        }
+    }
+}
+
+pub fn despawn_remote_players(
+    mut commands: Commands,
+    remote_player_query: Query<Entity, With<RemotePlayer>>,
+) {
+    for remote_player_entity in remote_player_query.iter() {
+        commands.entity(remote_player_entity).despawn();
+    }
+}
+
+pub fn send_snapshots(
+    network_socket: Res<NetworkSocket>,
+    mut snapshot_timer: ResMut<SnapshotTimer>,
+    entity_query: Query<ReplicatedItem>,
+    score: Option<Res<Score>>,
+    time: Res<Time>,
+) {
+    snapshot_timer.timer.tick(time.delta());
+    if !snapshot_timer.timer.just_finished() {
+        return;
+    }
+    snapshot_timer.tick = snapshot_timer.tick.wrapping_add(1);
+
+    let entities = entity_query
+        .iter()
+        .map(
+            |(network_id, transform, remote_player, player, enemy_kind, star)| {
+                let kind = match (remote_player, player, enemy_kind) {
+                    (Some(remote_player), _, _) => EntityKind::Player {
+                        player_id: remote_player.player_id,
+                    },
+                    (_, Some(_), _) => EntityKind::Player {
+                        player_id: LOCAL_PLAYER_ID,
+                    },
+                    (_, _, Some(enemy_kind)) => EntityKind::Enemy { kind: *enemy_kind },
+                    _ => EntityKind::Star {
+                        kind: star.map(|star| star.kind).unwrap_or_default(),
+                    },
+                };
+                EntityState {
+                    id: network_id.0,
+                    kind,
+                    position: transform.translation.truncate().into(),
+                }
+            },
+        )
+        .collect();
+
+    let snapshot = Snapshot {
+        tick: snapshot_timer.tick,
+        part: 0,
+        parts: 1,
+        score: score.map_or(0, |score| score.value),
+        entities,
+    };
+    for part in snapshot.split(MAX_PACKET_SIZE) {
+        send_message(&network_socket, &NetMessage::Snapshot(part));
+    }
+}
+
+pub fn send_game_over(
+    network_socket: Res<NetworkSocket>,
+    mut game_over_event_reader: EventReader<GameOver>,
+) {
+    for event in game_over_event_reader.iter() {
+        send_message(
+            &network_socket,
+            &NetMessage::GameOver { score: event.score },
+        );
+    }
+}
+
+pub fn send_input(
+    network_socket: Res<NetworkSocket>,
+    network_role: Res<NetworkRole>,
+    keyboard_input: Res<Input<KeyCode>>,
+) {
+    if let NetworkRole::Client { player_id, .. } = *network_role {
+        let direction = keyboard_direction(&keyboard_input).truncate();
+        send_message(
+            &network_socket,
+            &NetMessage::Input {
+                player_id,
+                direction: direction.into(),
+            },
+        );
+    }
+}
+
+pub fn receive_host_messages(
+    network_socket: Res<NetworkSocket>,
+    mut received_snapshots: ResMut<ReceivedSnapshots>,
+    mut game_over_event_writer: EventWriter<GameOver>,
+) {
+    for message in receive_messages(&network_socket) {
+        match message {
+            NetMessage::Snapshot(snapshot) => received_snapshots.add(snapshot),
+            NetMessage::GameOver { score } => game_over_event_writer.send(GameOver { score }),
+            NetMessage::Input { .. } => {}
+        }
+    }
+}
+
+pub fn apply_snapshot(
+    mut commands: Commands,
+    network_role: Res<NetworkRole>,
+    mut received_snapshots: ResMut<ReceivedSnapshots>,
+    mut replicated_query: Query<(Entity, &NetworkId, &Transform, &mut Interpolation)>,
+    score: Option<ResMut<Score>>,
+    game_assets: Res<GameAssets>,
+    game_config: Res<GameConfig>,
+) {
+    let Some(snapshot) = received_snapshots.complete.take() else {
+        return;
+    };
+
+    if let Some(mut score) = score {
+        if score.value != snapshot.score {
+            score.value = snapshot.score;
+        }
+    }
+
+    let mut states: HashMap<u32, EntityState> = snapshot
+        .entities
+        .into_iter()
+        .map(|state| (state.id, state))
+        .collect();
+
+    for (entity, network_id, transform, mut interpolation) in replicated_query.iter_mut() {
+        match states.remove(&network_id.0) {
+            Some(state) => {
+                interpolation.start = transform.translation.truncate();
+                interpolation.end = Vec2::from(state.position);
+                interpolation.elapsed = 0.0;
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    let own_player_id = match *network_role {
+        NetworkRole::Client { player_id, .. } => Some(player_id),
+        _ => None,
+    };
+    for state in states.into_values() {
+        let (texture, color) = match state.kind {
+            EntityKind::Player { player_id } if Some(player_id) == own_player_id => {
+                (game_assets.player.clone(), Color::WHITE)
+            }
+            EntityKind::Player { .. } => (game_assets.player.clone(), OTHER_PLAYER_COLOUR),
+            EntityKind::Enemy { kind } => (game_assets.enemy(kind), Color::WHITE),
+            EntityKind::Star { kind } => {
+                let (r, g, b, a) = game_config.stars.variant(kind).tint;
+                (game_assets.star(kind), Color::rgba(r, g, b, a))
+            }
+        };
+        let position = Vec2::from(state.position);
+
+        commands.spawn((
+            SpriteBundle {
+                transform: Transform::from_translation(position.extend(0.0)),
+                texture,
+                sprite: Sprite { color, ..default() },
+                ..default()
+            },
+            NetworkId(state.id),
+            Interpolation {
+                start: position,
+                end: position,
+                elapsed: 0.0,
+            },
+        ));
+    }
+}
+
+pub fn interpolate_replicated_entities(
+    mut replicated_query: Query<(&mut Transform, &mut Interpolation)>,
+    snapshot_timer: Res<SnapshotTimer>,
+    time: Res<Time>,
+) {
+    let snapshot_interval = snapshot_timer.timer.duration().as_secs_f32();
+
+    for (mut transform, mut interpolation) in replicated_query.iter_mut() {
+        interpolation.elapsed += time.delta_seconds();
+        let t = (interpolation.elapsed / snapshot_interval).min(1.0);
+        let position = interpolation.start.lerp(interpolation.end, t);
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
+
+pub fn despawn_replicated_entities(
+    mut commands: Commands,
+    replicated_query: Query<Entity, With<Interpolation>>,
+    mut received_snapshots: ResMut<ReceivedSnapshots>,
+) {
+    for entity in replicated_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    *received_snapshots = ReceivedSnapshots::default();
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+// Pooled particles are never despawned; an inactive one is hidden and waiting in
+// `ParticlePool::free`.
+#[derive(Component)]
+pub struct Particle {
+    pub active: bool,
+    pub velocity: Vec2,
+    pub drag: f32,
+    pub age: f32,
+    pub lifetime: f32,
+    pub start_size: f32,
+    pub end_size: f32,
+    pub start_colour: Color,
+    pub end_colour: Color,
+}
+
+impl Default for Particle {
+    fn default() -> Particle {
+        Particle {
+            active: false,
+            velocity: Vec2::ZERO,
+            drag: 0.0,
+            age: 0.0,
+            lifetime: 1.0,
+            start_size: 0.0,
+            end_size: 0.0,
+            start_colour: Color::WHITE,
+            end_colour: Color::WHITE,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod resources;
+mod systems;
+
+use resources::*;
+use systems::*;
+
+use crate::AppState;
+
+use super::SimulationState;
+
+pub const PARTICLE_Z: f32 = 1.0; // Drawn above the balls and stars.
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct EmitParticlesSystemSet;
+
+pub struct ParticlesPlugin;
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParticlePool>()
+            .init_resource::<DeathBurst>()
+            .add_startup_system(spawn_particle_pool)
+            .add_system(
+                emit_particles
+                    .run_if(in_state(AppState::Game))
+                    .in_set(EmitParticlesSystemSet),
+            )
+            .add_system(
+                update_particles
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running)),
+            )
+            .add_systems((clear_particles, clear_death_burst).in_schedule(OnExit(AppState::Game)));
+    }
+}
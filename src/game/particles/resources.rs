@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct ParticlePool {
+    pub free: Vec<Entity>,
+}
+
+// Seconds until the last player death burst has faded, so game over can wait
+// for it before the arena and its particles are cleared away.
+#[derive(Resource, Default)]
+pub struct DeathBurst {
+    pub remaining: f32,
+}
+
+impl DeathBurst {
+    pub fn is_playing(&self) -> bool {
+        self.remaining > 0.0
+    }
+}
@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use super::components::Particle;
+use super::resources::{DeathBurst, ParticlePool};
+use super::PARTICLE_Z;
+use crate::config::{EmitterConfig, GameConfig};
+use crate::events::{EnemyBounced, PlayerHit, StarCollected};
+
+type ParticleItem<'a> = (
+    &'a mut Particle,
+    &'a mut Transform,
+    &'a mut Sprite,
+    &'a mut Visibility,
+);
+
+pub fn spawn_particle_pool(mut commands: Commands, game_config: Res<GameConfig>) {
+    let free = (0..game_config.particles.pool_size)
+        .map(|_| {
+            commands
+                .spawn((
+                    SpriteBundle {
+                        transform: Transform::from_xyz(0.0, 0.0, PARTICLE_Z),
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                    Particle::default(),
+                ))
+                .id()
+        })
+        .collect();
+
+    commands.insert_resource(ParticlePool { free });
+}
+
+pub fn emit_particles(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut enemy_bounced_event_reader: EventReader<EnemyBounced>,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut particle_pool: ResMut<ParticlePool>,
+    mut particle_query: Query<ParticleItem>,
+    mut death_burst: ResMut<DeathBurst>,
+    game_config: Res<GameConfig>,
+) {
+    let particles_config = &game_config.particles;
+
+    for event in star_collected_event_reader.iter() {
+        burst(
+            &particles_config.star_collected,
+            event.position,
+            None,
+            &mut particle_pool,
+            &mut particle_query,
+        );
+    }
+    for event in enemy_bounced_event_reader.iter() {
+        burst(
+            &particles_config.enemy_bounced,
+            event.position,
+            Some(event.normal),
+            &mut particle_pool,
+            &mut particle_query,
+        );
+    }
+    for event in player_hit_event_reader.iter() {
+        burst(
+            &particles_config.player_death,
+            event.position,
+            None,
+            &mut particle_pool,
+            &mut particle_query,
+        );
+        let (min_lifetime, max_lifetime) = particles_config.player_death.lifetime;
+        death_burst.remaining = min_lifetime.max(max_lifetime);
+    }
+}
+
+fn burst(
+    emitter: &EmitterConfig,
+    position: Vec2,
+    direction: Option<Vec2>,
+    particle_pool: &mut ParticlePool,
+    particle_query: &mut Query<ParticleItem>,
+) {
+    let mut rng = thread_rng();
+    let base_angle = direction.map_or(0.0, |direction| direction.y.atan2(direction.x));
+    let half_spread = emitter.spread.abs().to_radians() / 2.0;
+
+    for _ in 0..emitter.count {
+        // An empty pool just means a smaller burst.
+        let Some(entity) = particle_pool.free.pop() else {
+            return;
+        };
+        let Ok((mut particle, mut transform, mut sprite, mut visibility)) =
+            particle_query.get_mut(entity)
+        else {
+            continue;
+        };
+
+        let angle = base_angle + rng.gen_range(-half_spread..=half_spread);
+        let speed = gen_between(&mut rng, emitter.speed);
+        let (r, g, b, a) = emitter.start_colour;
+        let (end_r, end_g, end_b, end_a) = emitter.end_colour;
+
+        *particle = Particle {
+            active: true,
+            velocity: Vec2::from_angle(angle) * speed,
+            drag: emitter.drag,
+            age: 0.0,
+            lifetime: gen_between(&mut rng, emitter.lifetime),
+            start_size: emitter.start_size,
+            end_size: emitter.end_size,
+            start_colour: Color::rgba(r, g, b, a),
+            end_colour: Color::rgba(end_r, end_g, end_b, end_a),
+        };
+        transform.translation = position.extend(PARTICLE_Z);
+        sprite.color = particle.start_colour;
+        sprite.custom_size = Some(Vec2::splat(particle.start_size));
+        *visibility = Visibility::Visible;
+    }
+}
+
+// The config's (min, max) pairs may be written either way round.
+fn gen_between(rng: &mut impl Rng, (a, b): (f32, f32)) -> f32 {
+    rng.gen_range(a.min(b)..=a.max(b))
+}
+
+pub fn update_particles(
+    mut particle_pool: ResMut<ParticlePool>,
+    mut particle_query: Query<(Entity, ParticleItem)>,
+    mut death_burst: ResMut<DeathBurst>,
+    time: Res<Time>,
+) {
+    // Real time, so a burst keeps flying while hit-stop has frozen the game.
+    let delta_seconds = time.raw_delta_seconds();
+    death_burst.remaining = (death_burst.remaining - delta_seconds).max(0.0);
+
+    for (entity, (mut particle, mut transform, mut sprite, mut visibility)) in
+        particle_query.iter_mut()
+    {
+        if !particle.active {
+            continue;
+        }
+
+        particle.age += delta_seconds;
+        if particle.age >= particle.lifetime {
+            particle.active = false;
+            *visibility = Visibility::Hidden;
+            particle_pool.free.push(entity);
+            continue;
+        }
+
+        let drag = (1.0 - particle.drag * delta_seconds).max(0.0);
+        particle.velocity *= drag;
+        transform.translation += (particle.velocity * delta_seconds).extend(0.0);
+
+        let t = particle.age / particle.lifetime;
+        let size = particle.start_size + (particle.end_size - particle.start_size) * t;
+        let start = Vec4::from(particle.start_colour.as_rgba_f32());
+        let end = Vec4::from(particle.end_colour.as_rgba_f32());
+        sprite.custom_size = Some(Vec2::splat(size));
+        sprite.color = Color::from(start.lerp(end, t));
+    }
+}
+
+pub fn clear_particles(
+    mut particle_pool: ResMut<ParticlePool>,
+    mut particle_query: Query<(Entity, &mut Particle, &mut Visibility)>,
+) {
+    for (entity, mut particle, mut visibility) in particle_query.iter_mut() {
+        if particle.active {
+            particle.active = false;
+            *visibility = Visibility::Hidden;
+            particle_pool.free.push(entity);
+        }
+    }
+}
+
+pub fn clear_death_burst(mut death_burst: ResMut<DeathBurst>) {
+    death_burst.remaining = 0.0;
+}
@@ -2,3 +2,31 @@ use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct Player {}
+
+// Pixels per second. Shared by local and remote players.
+#[derive(Component, Default)]
+pub struct Velocity {
+    pub value: Vec2,
+}
+
+// Seconds left on each part of the dash; all zero when it is ready.
+#[derive(Component, Default)]
+pub struct Dash {
+    pub time_remaining: f32,
+    pub invulnerability_remaining: f32,
+    pub cooldown_remaining: f32,
+}
+
+impl Dash {
+    pub fn is_dashing(&self) -> bool {
+        self.time_remaining > 0.0
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerability_remaining > 0.0
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.cooldown_remaining <= 0.0
+    }
+}
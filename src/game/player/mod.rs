@@ -5,8 +5,14 @@ mod systems;
 
 use systems::*;
 
+pub use systems::{bounce_off_walls, keyboard_direction, steer, PLAYER_SIZE, PLAYER_SPEED};
+
+use crate::events::LevelStarted;
 use crate::AppState;
 
+use super::modifiers::arena_has_walls;
+use super::network::is_authority;
+use super::spawner::SpawnSystemSet;
 use super::SimulationState;
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -21,7 +27,16 @@ impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.configure_set(MovementSystemSet.before(ConfinementSystemSet))
             // .add_startup_system(spawn_player)
-            .add_system(spawn_player.in_schedule(OnEnter(AppState::Game)))
+            // Applied straight away so the opening wave can aim at the player
+            // and keep clear of them.
+            .add_systems(
+                (spawn_player, apply_system_buffers)
+                    .chain()
+                    .before(SpawnSystemSet)
+                    .in_set(OnUpdate(AppState::Game))
+                    .distributive_run_if(is_authority)
+                    .distributive_run_if(on_event::<LevelStarted>()),
+            )
             // .add_systems(
             //     (
             //         player_movement,
@@ -46,17 +61,23 @@ impl Plugin for PlayerPlugin {
             .add_systems(
                 (
                     player_movement.in_set(MovementSystemSet),
-                    confine_player_movement.in_set(ConfinementSystemSet),
+                    confine_player_movement
+                        .in_set(ConfinementSystemSet)
+                        .run_if(arena_has_walls),
+                    show_invulnerability,
                 )
                     .in_set(OnUpdate(AppState::Game))
-                    .in_set(OnUpdate(SimulationState::Running)),
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
             )
             // .add_system(enemy_hit_player)
             // .add_system(player_hit_star)
             .add_systems(
-                (enemy_hit_player, player_hit_star)
+                (enemy_hit_player, player_hit_star, detect_near_misses)
+                    .after(ConfinementSystemSet)
                     .in_set(OnUpdate(AppState::Game))
-                    .in_set(OnUpdate(SimulationState::Running)),
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
             )
             .add_system(despawn_player.in_schedule(OnExit(AppState::Game)));
     }
@@ -1,31 +1,44 @@
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
-use super::components::Player;
-use crate::events::GameOver;
+use super::components::{Dash, Player, Velocity};
+use crate::assets::GameAssets;
+use crate::config::{GameConfig, PlayerConfig};
+use crate::events::{NearMiss, PlayerHit, StarCollected};
+use crate::game::collision::components::PreviousPosition;
+use crate::game::collision::time_of_impact;
 use crate::game::enemy::components::*;
 use crate::game::enemy::ENEMY_SIZE;
-use crate::game::score::resources::*;
+use crate::game::level::resources::ActiveLevel;
+use crate::game::modifiers::resources::{Modifier, RunModifiers};
+use crate::game::network::LOCAL_PLAYER_ID;
 use crate::game::star::components::Star;
 use crate::game::star::STAR_SIZE;
 
 pub const PLAYER_SPEED: f32 = 500.0;
 pub const PLAYER_SIZE: f32 = 64.0; // This is the player sprite size.
+pub const NEAR_MISS_MARGIN: f32 = 32.0; // Gap between the balls that still counts as close.
+pub const INVULNERABILITY_FLICKER_RATE: f32 = 15.0; // Alpha toggles per second.
 
 pub fn spawn_player(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    active_level: Res<ActiveLevel>,
 ) {
-    let window = window_query.get_single().unwrap();
+    let position = active_level.player_start();
 
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_xyz(window.width() / 2.0, window.height() / 2.0, 0.0),
-            texture: asset_server.load("sprites/ball_blue_large.png"),
+            transform: Transform::from_xyz(position.x, position.y, 0.0),
+            texture: game_assets.player.clone(),
             ..default()
         },
         Player {},
+        Velocity::default(),
+        Dash::default(),
+        PreviousPosition { value: position },
     ));
 }
 
@@ -37,87 +50,167 @@ pub fn despawn_player(mut commands: Commands, player_query: Query<Entity, With<P
 
 pub fn player_movement(
     keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<&mut Transform, With<Player>>,
+    mut player_query: Query<(&mut Transform, &mut Velocity, &mut Dash), With<Player>>,
+    game_config: Res<GameConfig>,
+    run_modifiers: Res<RunModifiers>,
     time: Res<Time>,
 ) {
-    if let Ok(mut transform) = player_query.get_single_mut() {
-        let mut direction = Vec3::ZERO;
-
-        if keyboard_input.pressed(KeyCode::Left) || keyboard_input.pressed(KeyCode::A) {
-            direction += Vec3::new(-1.0, 0.0, 0.0);
-        }
-        if keyboard_input.pressed(KeyCode::Right) || keyboard_input.pressed(KeyCode::D) {
-            direction += Vec3::new(1.0, 0.0, 0.0);
-        }
-        if keyboard_input.pressed(KeyCode::Up) || keyboard_input.pressed(KeyCode::W) {
-            direction += Vec3::new(0.0, 1.0, 0.0);
+    if let Ok((mut transform, mut velocity, mut dash)) = player_query.get_single_mut() {
+        let mut direction = keyboard_direction(&keyboard_input).truncate();
+        if run_modifiers.has(Modifier::MirroredControls) {
+            direction.x = -direction.x;
         }
-        if keyboard_input.pressed(KeyCode::Down) || keyboard_input.pressed(KeyCode::S) {
-            direction += Vec3::new(0.0, -1.0, 0.0);
+        let dash_config = &game_config.player.dash;
+
+        dash.time_remaining -= time.delta_seconds();
+        dash.invulnerability_remaining -= time.delta_seconds();
+        dash.cooldown_remaining -= time.delta_seconds();
+
+        if dash.is_ready() && dash_pressed(&keyboard_input) {
+            // Dash where the player is steering, or along the roll if no key is held.
+            let dash_direction = if direction != Vec2::ZERO {
+                direction
+            } else {
+                velocity.value.normalize_or_zero()
+            };
+            if dash_direction != Vec2::ZERO {
+                velocity.value = dash_direction * dash_config.speed;
+                dash.time_remaining = dash_config.duration;
+                dash.invulnerability_remaining = dash_config.invulnerability;
+                dash.cooldown_remaining = dash_config.cooldown;
+            }
         }
 
-        if direction.length() > 0.0 {
-            direction = direction.normalize();
+        // The burst ignores steering and the speed cap until it runs out.
+        if !dash.is_dashing() {
+            velocity.value = steer(
+                velocity.value,
+                direction,
+                &game_config.player,
+                time.delta_seconds(),
+            );
         }
+        transform.translation += velocity.value.extend(0.0) * time.delta_seconds();
+    }
+}
 
-        transform.translation += direction * PLAYER_SPEED * time.delta_seconds();
+fn dash_pressed(keyboard_input: &Input<KeyCode>) -> bool {
+    keyboard_input.any_just_pressed([KeyCode::LShift, KeyCode::RShift])
+}
+
+// Moves a player's velocity one frame towards the input direction.
+pub fn steer(velocity: Vec2, direction: Vec2, player_config: &PlayerConfig, delta: f32) -> Vec2 {
+    if !player_config.momentum {
+        return direction * player_config.max_speed;
     }
+
+    let velocity = velocity + direction * player_config.acceleration * delta;
+    let velocity = velocity * (1.0 - player_config.drag * delta).max(0.0);
+    velocity.clamp_length_max(player_config.max_speed)
+}
+
+// Pushes a ball back inside `min..max`, reflecting the velocity off any wall it hit.
+pub fn bounce_off_walls(
+    position: &mut Vec2,
+    velocity: &mut Vec2,
+    min: Vec2,
+    max: Vec2,
+    bounciness: f32,
+) {
+    if (position.x < min.x && velocity.x < 0.0) || (position.x > max.x && velocity.x > 0.0) {
+        velocity.x *= -bounciness;
+    }
+    if (position.y < min.y && velocity.y < 0.0) || (position.y > max.y && velocity.y > 0.0) {
+        velocity.y *= -bounciness;
+    }
+    *position = position.clamp(min, max);
+}
+
+// Normalised movement direction from the arrow keys / WASD.
+pub fn keyboard_direction(keyboard_input: &Input<KeyCode>) -> Vec3 {
+    let mut direction = Vec3::ZERO;
+
+    if keyboard_input.pressed(KeyCode::Left) || keyboard_input.pressed(KeyCode::A) {
+        direction += Vec3::new(-1.0, 0.0, 0.0);
+    }
+    if keyboard_input.pressed(KeyCode::Right) || keyboard_input.pressed(KeyCode::D) {
+        direction += Vec3::new(1.0, 0.0, 0.0);
+    }
+    if keyboard_input.pressed(KeyCode::Up) || keyboard_input.pressed(KeyCode::W) {
+        direction += Vec3::new(0.0, 1.0, 0.0);
+    }
+    if keyboard_input.pressed(KeyCode::Down) || keyboard_input.pressed(KeyCode::S) {
+        direction += Vec3::new(0.0, -1.0, 0.0);
+    }
+
+    if direction.length() > 0.0 {
+        direction = direction.normalize();
+    }
+
+    direction
 }
 
 pub fn confine_player_movement(
-    mut player_query: Query<&mut Transform, With<Player>>,
+    mut player_query: Query<(&mut Transform, &mut Velocity), With<Player>>,
     window_query: Query<&Window, With<PrimaryWindow>>,
+    game_config: Res<GameConfig>,
 ) {
-    if let Ok(mut player_transform) = player_query.get_single_mut() {
+    if let Ok((mut player_transform, mut velocity)) = player_query.get_single_mut() {
         let window = window_query.get_single().unwrap();
 
-        let half_player_size = PLAYER_SIZE / 2.0; // 32.0
-        let x_min = 0.0 + half_player_size;
-        let x_max = window.width() - half_player_size;
-        let y_min = 0.0 + half_player_size;
-        let y_max = window.height() - half_player_size;
+        // Scaled up by the big head mutator.
+        let half_player_size = PLAYER_SIZE * player_transform.scale.x / 2.0;
+        let min = Vec2::splat(half_player_size);
+        let max = Vec2::new(window.width(), window.height()) - half_player_size;
 
-        let mut translation = player_transform.translation;
+        let mut position = player_transform.translation.truncate();
+        bounce_off_walls(
+            &mut position,
+            &mut velocity.value,
+            min,
+            max,
+            game_config.player.wall_bounciness,
+        );
 
-        // Bound the player x position
-        if translation.x < x_min {
-            translation.x = x_min;
-        } else if translation.x > x_max {
-            translation.x = x_max;
-        }
-        // Bound the players y position.
-        if translation.y < y_min {
-            translation.y = y_min;
-        } else if translation.y > y_max {
-            translation.y = y_max;
-        }
-
-        player_transform.translation = translation;
+        player_transform.translation = position.extend(player_transform.translation.z);
     }
 }
 
+// Only reports the hit; the game mode decides whether it ends the run.
 pub fn enemy_hit_player(
     mut commands: Commands,
-    mut game_over_event_writer: EventWriter<GameOver>,
-    mut player_query: Query<(Entity, &Transform), With<Player>>,
-    enemy_query: Query<&Transform, With<Enemy>>,
-    asset_server: Res<AssetServer>,
-    audio: Res<Audio>,
-    score: Res<Score>,
+    mut player_hit_event_writer: EventWriter<PlayerHit>,
+    mut player_query: Query<(Entity, &Transform, &PreviousPosition, &Dash), With<Player>>,
+    enemy_query: Query<(Entity, &Transform, &PreviousPosition, &EnemyKind), With<Enemy>>,
 ) {
-    if let Ok((player_entity, player_transform)) = player_query.get_single_mut() {
-        for enemy_transform in enemy_query.iter() {
-            let distance = player_transform
-                .translation
-                .distance(enemy_transform.translation);
-            let player_radius = PLAYER_SIZE / 2.0;
+    if let Ok((player_entity, player_transform, player_previous, dash)) =
+        player_query.get_single_mut()
+    {
+        if dash.is_invulnerable() {
+            return;
+        }
+
+        let player_motion = player_transform.translation.truncate() - player_previous.value;
+        for (enemy_entity, enemy_transform, enemy_previous, enemy_kind) in enemy_query.iter() {
+            let enemy_motion = enemy_transform.translation.truncate() - enemy_previous.value;
+            let player_radius = PLAYER_SIZE * player_transform.scale.x / 2.0;
             let enemy_radius = ENEMY_SIZE / 2.0;
-            if distance < player_radius + enemy_radius {
-                println!("Enemy hit player! Game Over!");
-                let sound_effect = asset_server.load("audio/explosionCrunch_000.ogg");
-                audio.play(sound_effect);
+            // Swept, so a fast ball can't pass straight through the player between frames.
+            if time_of_impact(
+                enemy_previous.value - player_previous.value,
+                enemy_motion - player_motion,
+                player_radius + enemy_radius,
+            )
+            .is_some()
+            {
+                player_hit_event_writer.send(PlayerHit {
+                    player_id: LOCAL_PLAYER_ID,
+                    enemy: enemy_entity,
+                    enemy_kind: *enemy_kind,
+                    position: player_transform.translation.truncate(),
+                });
                 commands.entity(player_entity).despawn();
-                game_over_event_writer.send(GameOver { score: score.value });
+                break;
             }
         }
     }
@@ -126,24 +219,164 @@ pub fn enemy_hit_player(
 pub fn player_hit_star(
     mut commands: Commands,
     player_query: Query<&Transform, With<Player>>,
-    star_query: Query<(Entity, &Transform), With<Star>>,
-    asset_server: Res<AssetServer>,
-    audio: Res<Audio>,
-    mut score: ResMut<Score>,
+    star_query: Query<(Entity, &Transform, &Star)>,
+    game_config: Res<GameConfig>,
+    mut star_collected_event_writer: EventWriter<StarCollected>,
 ) {
     if let Ok(player_transform) = player_query.get_single() {
-        for (star_entity, star_transform) in star_query.iter() {
+        for (star_entity, star_transform, star) in star_query.iter() {
             let distance = player_transform
                 .translation
                 .distance(star_transform.translation);
 
-            if distance < PLAYER_SIZE / 2.0 + STAR_SIZE / 2.0 {
-                println!("Player hit star!");
-                score.value += 1;
-                let sound_effect = asset_server.load("audio/laserLarge_000.ogg");
-                audio.play(sound_effect);
+            let player_radius = PLAYER_SIZE * player_transform.scale.x / 2.0;
+            if distance < player_radius + STAR_SIZE * star_transform.scale.x / 2.0 {
+                star_collected_event_writer.send(StarCollected {
+                    player_id: LOCAL_PLAYER_ID,
+                    position: star_transform.translation.truncate(),
+                    value: star.value(&game_config.stars),
+                });
                 commands.entity(star_entity).despawn();
             }
         }
     }
 }
+
+pub fn detect_near_misses(
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    mut near_enemies: Local<HashSet<Entity>>,
+    mut near_miss_event_writer: EventWriter<NearMiss>,
+) {
+    // No player means it was just hit, and a hit isn't a miss.
+    let Ok(player_transform) = player_query.get_single() else {
+        near_enemies.clear();
+        return;
+    };
+
+    let near_distance =
+        PLAYER_SIZE * player_transform.scale.x / 2.0 + ENEMY_SIZE / 2.0 + NEAR_MISS_MARGIN;
+    let still_near: HashSet<Entity> = enemy_query
+        .iter()
+        .filter(|(_, enemy_transform)| {
+            player_transform
+                .translation
+                .distance(enemy_transform.translation)
+                < near_distance
+        })
+        .map(|(enemy_entity, _)| enemy_entity)
+        .collect();
+
+    for enemy_entity in near_enemies.difference(&still_near) {
+        if enemy_query.contains(*enemy_entity) {
+            near_miss_event_writer.send(NearMiss {
+                enemy: *enemy_entity,
+            });
+        }
+    }
+    *near_enemies = still_near;
+}
+
+// Flickers the player while enemies can pass through it.
+pub fn show_invulnerability(
+    mut player_query: Query<(&Dash, &mut Sprite), With<Player>>,
+    time: Res<Time>,
+) {
+    if let Ok((dash, mut sprite)) = player_query.get_single_mut() {
+        let flicker =
+            ((time.elapsed_seconds() * INVULNERABILITY_FLICKER_RATE) as u32).is_multiple_of(2);
+        let alpha = if dash.is_invulnerable() && flicker {
+            0.4
+        } else {
+            1.0
+        };
+        sprite.color.set_a(alpha);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME_SECONDS: f32 = 1.0 / 60.0;
+
+    #[test]
+    fn without_momentum_the_input_is_the_velocity() {
+        let player_config = PlayerConfig {
+            momentum: false,
+            ..default()
+        };
+
+        let velocity = steer(
+            Vec2::new(300.0, 0.0),
+            Vec2::Y,
+            &player_config,
+            FRAME_SECONDS,
+        );
+        assert_eq!(velocity, Vec2::Y * player_config.max_speed);
+        assert_eq!(
+            steer(velocity, Vec2::ZERO, &player_config, FRAME_SECONDS),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn momentum_speeds_up_to_the_limit_and_drags_to_a_stop() {
+        let player_config = PlayerConfig::default();
+
+        let mut velocity = Vec2::ZERO;
+        velocity = steer(velocity, Vec2::X, &player_config, FRAME_SECONDS);
+        assert!(velocity.x > 0.0 && velocity.x < player_config.max_speed);
+
+        for _ in 0..600 {
+            velocity = steer(velocity, Vec2::X, &player_config, FRAME_SECONDS);
+            assert!(velocity.length() <= player_config.max_speed + 1e-3);
+        }
+        assert!(velocity.x > player_config.max_speed * 0.9);
+
+        for _ in 0..600 {
+            velocity = steer(velocity, Vec2::ZERO, &player_config, FRAME_SECONDS);
+        }
+        assert!(velocity.length() < 1.0);
+    }
+
+    #[test]
+    fn a_huge_frame_never_reverses_the_velocity() {
+        let player_config = PlayerConfig::default();
+
+        let velocity = steer(Vec2::new(400.0, 0.0), Vec2::ZERO, &player_config, 10.0);
+        assert_eq!(velocity, Vec2::ZERO);
+    }
+
+    #[test]
+    fn walls_reflect_and_damp_the_velocity() {
+        let (min, max) = (Vec2::splat(10.0), Vec2::splat(100.0));
+
+        let mut position = Vec2::new(5.0, 120.0);
+        let mut velocity = Vec2::new(-200.0, 100.0);
+        bounce_off_walls(&mut position, &mut velocity, min, max, 0.5);
+        assert_eq!(position, Vec2::new(10.0, 100.0));
+        assert_eq!(velocity, Vec2::new(100.0, -50.0));
+    }
+
+    #[test]
+    fn walls_leave_balls_already_heading_back_alone() {
+        let (min, max) = (Vec2::splat(10.0), Vec2::splat(100.0));
+
+        // Still outside after last frame's bounce; bouncing again would send
+        // it back into the wall.
+        let mut position = Vec2::new(5.0, 50.0);
+        let mut velocity = Vec2::new(100.0, 0.0);
+        bounce_off_walls(&mut position, &mut velocity, min, max, 0.5);
+        assert_eq!(position, Vec2::new(10.0, 50.0));
+        assert_eq!(velocity, Vec2::new(100.0, 0.0));
+
+        let mut position = Vec2::new(50.0, 50.0);
+        let mut velocity = Vec2::new(-100.0, 100.0);
+        bounce_off_walls(&mut position, &mut velocity, min, max, 0.5);
+        assert_eq!(
+            (position, velocity),
+            (Vec2::new(50.0, 50.0), Vec2::new(-100.0, 100.0))
+        );
+    }
+}
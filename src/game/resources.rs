@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+// Drives every gameplay roll: where things spawn, which way enemies head and
+// which stars appear. Reseeded at the start of each run, with a fixed seed for
+// the daily challenge so everyone gets the same spawns.
+#[derive(Resource)]
+pub struct GameRng {
+    pub rng: StdRng,
+}
+
+impl Default for GameRng {
+    fn default() -> GameRng {
+        GameRng {
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
@@ -1,24 +1,39 @@
 use bevy::prelude::*;
 
-use bevy::prelude::*;
-
 pub mod resources;
 mod systems;
 
 use resources::*;
 use systems::*;
 
+use crate::game::network::is_authority;
+use crate::game::SimulationState;
 use crate::AppState;
 
+pub const COMBO_WINDOW: f32 = 2.0; // Seconds allowed between stars to keep a combo going.
+pub const COMBO_MILESTONE: u32 = 5;
+pub const MAX_HIGH_SCORES: usize = 10; // Entries kept per game mode.
+
 pub struct ScorePlugin;
 
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
-        app
-            .init_resource::<HighScores>()
+        app.insert_resource(HighScores::load())
             .add_system(insert_score.in_schedule(OnEnter(AppState::Game)))
+            .add_system(
+                add_collected_stars
+                    .run_if(in_state(AppState::Game))
+                    .run_if(is_authority),
+            )
+            .add_system(
+                update_combo
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .run_if(is_authority),
+            )
             .add_system(update_score.run_if(in_state(AppState::Game)))
-            .add_system(update_high_scores)
+            // Clients only watch the host's game; the score isn't theirs to keep.
+            .add_system(update_high_scores.run_if(is_authority))
             .add_system(high_scores_updated)
             .add_system(remove_score.in_schedule(OnExit(AppState::Game)));
     }
@@ -1,4 +1,13 @@
+use std::collections::BTreeMap;
+
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::MAX_HIGH_SCORES;
+use crate::game::mode::resources::GameMode;
+use crate::persistence;
+
+pub const HIGH_SCORES_FILE: &str = "high_scores.ron";
 
 #[derive(Resource)]
 pub struct Score {
@@ -11,13 +20,50 @@ impl Default for Score {
     }
 }
 
-#[derive(Resource, Debug)]
+// One table per game mode, best first, since their scores don't compare.
+// Daily challenges get a table per day instead, as each day is a different run.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
 pub struct HighScores {
-    pub scores: Vec<(String, u32)>,
+    pub tables: BTreeMap<GameMode, Vec<(String, u32)>>,
+    pub daily: BTreeMap<String, Vec<(String, u32)>>,
 }
 
-impl Default for HighScores {
-    fn default() -> HighScores {
-        HighScores { scores: Vec::new() }
+impl HighScores {
+    pub fn load() -> HighScores {
+        persistence::load_or_default(HIGH_SCORES_FILE)
+    }
+
+    pub fn save(&self) {
+        persistence::save(HIGH_SCORES_FILE, self);
+    }
+
+    pub fn table(&self, game_mode: GameMode) -> &[(String, u32)] {
+        self.tables.get(&game_mode).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn add(&mut self, game_mode: GameMode, name: String, score: u32) {
+        insert_high_score(self.tables.entry(game_mode).or_default(), name, score);
     }
+
+    pub fn daily_table(&self, date: &str) -> &[(String, u32)] {
+        self.daily.get(date).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn add_daily(&mut self, date: &str, name: String, score: u32) {
+        insert_high_score(self.daily.entry(date.to_string()).or_default(), name, score);
+    }
+}
+
+fn insert_high_score(table: &mut Vec<(String, u32)>, name: String, score: u32) {
+    let index = table.partition_point(|(_, existing)| *existing >= score);
+    table.insert(index, (name, score));
+    table.truncate(MAX_HIGH_SCORES);
+}
+
+// Stars collected with less than `COMBO_WINDOW` seconds between them.
+#[derive(Resource, Default)]
+pub struct Combo {
+    pub count: u32,
+    pub since_last_star: f32,
 }
@@ -1,14 +1,53 @@
 use bevy::prelude::*;
 
 use super::resources::*;
-use crate::events::GameOver;
+use super::{COMBO_MILESTONE, COMBO_WINDOW};
+use crate::campaign::resources::CampaignRun;
+use crate::editor::resources::EditorSession;
+use crate::events::{ComboReached, GameOver, StarCollected};
+use crate::game::daily::resources::DailyChallenge;
+use crate::game::mode::resources::GameMode;
+use crate::game::modifiers::resources::RunModifiers;
 
 pub fn insert_score(mut commands: Commands) {
     commands.insert_resource(Score::default());
+    commands.insert_resource(Combo::default());
 }
 
 pub fn remove_score(mut commands: Commands) {
     commands.remove_resource::<Score>();
+    commands.remove_resource::<Combo>();
+}
+
+pub fn add_collected_stars(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut score: ResMut<Score>,
+    run_modifiers: Res<RunModifiers>,
+) {
+    let multiplier = run_modifiers.score_multiplier();
+    for event in star_collected_event_reader.iter() {
+        score.value += (event.value as f32 * multiplier).round() as u32;
+    }
+}
+
+pub fn update_combo(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut combo_reached_event_writer: EventWriter<ComboReached>,
+    mut combo: ResMut<Combo>,
+    time: Res<Time>,
+) {
+    combo.since_last_star += time.delta_seconds();
+    if combo.since_last_star > COMBO_WINDOW {
+        combo.count = 0;
+    }
+
+    for _ in star_collected_event_reader.iter() {
+        combo.count += 1;
+        combo.since_last_star = 0.0;
+        if combo.count.is_multiple_of(COMBO_MILESTONE) {
+            combo_reached_event_writer.send(ComboReached { combo: combo.count });
+        }
+    }
 }
 
 pub fn update_score(score: Res<Score>) {
@@ -17,17 +56,48 @@ pub fn update_score(score: Res<Score>) {
     }
 }
 
+// Campaign levels and editor test-plays only borrow a mode's rules, so their
+// scores stay out of its table.
 pub fn update_high_scores(
     mut game_over_event_reader: EventReader<GameOver>,
     mut high_scores: ResMut<HighScores>,
+    game_mode: Res<GameMode>,
+    daily_challenge: Res<DailyChallenge>,
+    campaign_run: Res<CampaignRun>,
+    editor_session: Res<EditorSession>,
 ) {
     for event in game_over_event_reader.iter() {
-        high_scores.scores.push(("Player".to_string(), event.score));
+        if campaign_run.level.is_some() || editor_session.test_playing {
+            continue;
+        }
+        let name = "Player".to_string();
+        match *game_mode {
+            GameMode::DailyChallenge => {
+                high_scores.add_daily(&daily_challenge.date, name, event.score)
+            }
+            game_mode => high_scores.add(game_mode, name, event.score),
+        }
+        high_scores.save();
     }
 }
 
-pub fn high_scores_updated(high_scores: Res<HighScores>) {
+pub fn high_scores_updated(
+    high_scores: Res<HighScores>,
+    game_mode: Res<GameMode>,
+    daily_challenge: Res<DailyChallenge>,
+) {
     if high_scores.is_changed() {
-        println!("High Scores: {:?}", high_scores);
+        match *game_mode {
+            GameMode::DailyChallenge => println!(
+                "Daily Challenge {} High Scores: {:?}",
+                daily_challenge.date,
+                high_scores.daily_table(&daily_challenge.date)
+            ),
+            game_mode => println!(
+                "{} High Scores: {:?}",
+                game_mode.name(),
+                high_scores.table(game_mode)
+            ),
+        }
     }
 }
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use super::ENEMY_PLAYER_CLEARANCE;
+use crate::game::enemy::components::EnemySpawn;
+use crate::game::enemy::ENEMY_SIZE;
+use crate::game::star::components::StarKind;
+use crate::game::star::STAR_SIZE;
+
+// The space a spawned entity takes up, so later spawns don't land on it.
+#[derive(Component)]
+pub struct Footprint {
+    pub radius: f32,
+}
+
+// Every kind of entity the spawner knows how to make. A new kind needs a
+// variant here, its rules below and a bundle in `Spawner::spawn`.
+#[derive(Debug, Clone, Copy)]
+pub enum SpawnKind {
+    Enemy(EnemySpawn),
+    Star(StarKind),
+}
+
+impl SpawnKind {
+    pub fn radius(&self) -> f32 {
+        match self {
+            SpawnKind::Enemy(_) => ENEMY_SIZE / 2.0,
+            SpawnKind::Star(_) => STAR_SIZE / 2.0,
+        }
+    }
+
+    // How close to a player, centre to centre, a randomly placed one may land.
+    pub fn player_clearance(&self) -> f32 {
+        match self {
+            SpawnKind::Enemy(_) => ENEMY_PLAYER_CLEARANCE,
+            SpawnKind::Star(_) => 0.0,
+        }
+    }
+}
+
+// Where a spawn goes. Everything is kept inside the arena; the random options
+// also keep clear of players and of anything already there.
+#[derive(Debug, Clone, Copy)]
+pub enum Placement {
+    // Exactly here, as a level designer placed it.
+    At(Vec2),
+    Anywhere,
+    // Inside one of the level's star regions, or anywhere if it has none.
+    StarRegion,
+    Around { centre: Vec2, radius: f32 },
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use systems::*;
+
+use crate::AppState;
+
+use super::network::is_authority;
+
+pub const SPAWN_ATTEMPTS: usize = 16; // Random spots tried before giving up on the spawn.
+pub const ENEMY_PLAYER_CLEARANCE: f32 = 200.0;
+
+// Systems that send `SpawnRequest`s run before this so the entities appear the
+// same frame.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct SpawnSystemSet;
+
+pub struct SpawnerPlugin;
+
+impl Plugin for SpawnerPlugin {
+    fn build(&self, app: &mut App) {
+        // Not held back by the pause, like the opening wave that asks for it.
+        app.add_system(
+            spawn_requested
+                .in_set(SpawnSystemSet)
+                .in_set(OnUpdate(AppState::Game))
+                .run_if(is_authority),
+        );
+    }
+}
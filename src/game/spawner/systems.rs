@@ -0,0 +1,287 @@
+use std::f32::consts::TAU;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use super::components::{Footprint, Placement, SpawnKind};
+use super::SPAWN_ATTEMPTS;
+use crate::assets::GameAssets;
+use crate::config::GameConfig;
+use crate::events::{EnemySpawned, SpawnRequest};
+use crate::game::enemy::components::EnemyBundle;
+use crate::game::enemy::spawn_velocity;
+use crate::game::level::resources::ActiveLevel;
+use crate::game::network::components::RemotePlayer;
+use crate::game::player::components::Player;
+use crate::game::resources::GameRng;
+use crate::game::star::components::{DriftingStar, StarBundle, StarKind, TeleportingStar};
+
+type PlayerFilter = Or<(With<Player>, With<RemotePlayer>)>;
+
+// Everything the placement rules look at, and what they place into.
+#[derive(SystemParam)]
+pub struct Spawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    game_assets: Res<'w, GameAssets>,
+    active_level: Res<'w, ActiveLevel>,
+    game_config: Res<'w, GameConfig>,
+    game_rng: ResMut<'w, GameRng>,
+    player_query: Query<'w, 's, &'static Transform, PlayerFilter>,
+    footprint_query: Query<'w, 's, (&'static Transform, &'static Footprint)>,
+    enemy_spawned_event_writer: EventWriter<'w, EnemySpawned>,
+}
+
+pub fn spawn_requested(
+    mut spawner: Spawner,
+    mut spawn_request_event_reader: EventReader<SpawnRequest>,
+) {
+    // Commands only land at the end of the frame, so spawns from earlier
+    // requests this frame are kept clear of by hand.
+    let mut placed = Vec::new();
+    for request in spawn_request_event_reader.iter() {
+        let Some(position) = spawner.place(request, &placed) else {
+            // Better one spawn fewer than one on top of the player.
+            println!("No clear spot for {:?}, skipping it", request.kind);
+            continue;
+        };
+        placed.push((position, request.kind.radius()));
+        spawner.spawn(request.kind, position);
+    }
+}
+
+impl<'w, 's> Spawner<'w, 's> {
+    fn place(&mut self, request: &SpawnRequest, placed: &[(Vec2, f32)]) -> Option<Vec2> {
+        let radius = request.kind.radius();
+        let min = Vec2::splat(radius);
+        let max = (self.active_level.world_size() - radius).max(min);
+
+        for _ in 0..SPAWN_ATTEMPTS {
+            let rng = &mut self.game_rng.rng;
+            let position = match request.placement {
+                Placement::At(position) => return Some(position.clamp(min, max)),
+                Placement::Anywhere => self.active_level.random_position(rng),
+                Placement::StarRegion => self.active_level.random_star_position(rng),
+                Placement::Around { centre, radius } => {
+                    centre + Vec2::from_angle(rng.gen::<f32>() * TAU) * rng.gen::<f32>() * radius
+                }
+            }
+            .clamp(min, max);
+
+            if self.is_clear(position, request.kind, placed) {
+                return Some(position);
+            }
+        }
+        None
+    }
+
+    // Until the player is spawned on the frame a level starts, they are
+    // wherever the level will put them.
+    fn player_positions(&self) -> Vec<Vec2> {
+        let mut player_positions: Vec<Vec2> = self
+            .player_query
+            .iter()
+            .map(|player_transform| player_transform.translation.truncate())
+            .collect();
+        if player_positions.is_empty() {
+            player_positions.push(self.active_level.player_start());
+        }
+        player_positions
+    }
+
+    // Obstacles are read from the level, since on the frame a level starts
+    // they haven't been spawned yet.
+    fn is_clear(&self, position: Vec2, kind: SpawnKind, placed: &[(Vec2, f32)]) -> bool {
+        let radius = kind.radius();
+        let active_level = &self.active_level;
+
+        let near_player = self
+            .player_positions()
+            .iter()
+            .any(|player_position| player_position.distance(position) < kind.player_clearance());
+
+        let overlapping = self
+            .footprint_query
+            .iter()
+            .map(|(transform, footprint)| (transform.translation.truncate(), footprint.radius))
+            .chain(placed.iter().copied())
+            .any(|(other, other_radius)| other.distance(position) < radius + other_radius);
+
+        let blocked = active_level.level.obstacles.iter().any(|obstacle| {
+            obstacle
+                .kind
+                .scaled(active_level.scale)
+                .contact(active_level.to_world(obstacle.position), position, radius)
+                .is_some()
+        });
+
+        !near_player && !overlapping && !blocked
+    }
+
+    fn spawn(&mut self, kind: SpawnKind, position: Vec2) {
+        match kind {
+            SpawnKind::Enemy(enemy_spawn) => {
+                // Aimed enemies go for whoever is closest.
+                let target = self
+                    .player_positions()
+                    .into_iter()
+                    .min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)));
+                let rng = &mut self.game_rng.rng;
+                let (direction, speed) = spawn_velocity(
+                    &enemy_spawn,
+                    self.game_config.enemies.kind(enemy_spawn.kind),
+                    position,
+                    target,
+                    rng,
+                );
+
+                let enemy_entity = self
+                    .commands
+                    .spawn(EnemyBundle::new(
+                        enemy_spawn.kind,
+                        position,
+                        direction,
+                        speed,
+                        self.game_assets.enemy(enemy_spawn.kind),
+                    ))
+                    .id();
+                self.enemy_spawned_event_writer.send(EnemySpawned {
+                    entity: enemy_entity,
+                    position,
+                });
+            }
+            SpawnKind::Star(star_kind) => {
+                let rng = &mut self.game_rng.rng;
+                let stars_config = &self.game_config.stars;
+                let mut star_entity = self.commands.spawn(StarBundle::new(
+                    star_kind,
+                    position,
+                    stars_config.variant(star_kind),
+                    self.game_assets.star(star_kind),
+                ));
+
+                match star_kind {
+                    StarKind::Drifting => {
+                        let angle = rng.gen::<f32>() * TAU;
+                        star_entity.insert(DriftingStar {
+                            velocity: Vec2::from_angle(angle) * stars_config.drift_speed,
+                        });
+                    }
+                    StarKind::Teleporting => {
+                        // Can't hop straight out from under a player it lands next to.
+                        star_entity.insert(TeleportingStar {
+                            cooldown: stars_config.teleport_cooldown,
+                        });
+                    }
+                    StarKind::Normal | StarKind::Gold => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::game::enemy::components::{Enemy, EnemyKind, EnemySpawn};
+    use crate::game::level::loader::Level;
+    use crate::game::spawner::ENEMY_PLAYER_CLEARANCE;
+
+    fn spawner_app(arena_size: (f32, f32)) -> App {
+        let mut app = App::new();
+        app.add_event::<SpawnRequest>()
+            .add_event::<EnemySpawned>()
+            .insert_resource(GameAssets {
+                font: default(),
+                player: default(),
+                enemies: HashMap::from([(EnemyKind::Bouncer, default())]),
+                stars: HashMap::new(),
+                bounce: Vec::new(),
+                pickup: default(),
+                death: default(),
+                music: HashMap::new(),
+            })
+            .insert_resource(ActiveLevel {
+                level: Level {
+                    arena_size,
+                    ..default()
+                },
+                scale: Vec2::ONE,
+            })
+            .insert_resource(GameConfig::default())
+            .insert_resource(GameRng {
+                rng: StdRng::seed_from_u64(49),
+            })
+            .add_system(spawn_requested);
+        app
+    }
+
+    fn request_enemies(app: &mut App, count: usize) {
+        for _ in 0..count {
+            app.world.send_event(SpawnRequest {
+                kind: SpawnKind::Enemy(EnemySpawn::default()),
+                placement: Placement::Anywhere,
+            });
+        }
+        app.update();
+    }
+
+    fn enemy_positions(app: &mut App) -> Vec<Vec2> {
+        app.world
+            .query_filtered::<&Transform, With<Enemy>>()
+            .iter(&app.world)
+            .map(|transform| transform.translation.truncate())
+            .collect()
+    }
+
+    #[test]
+    fn random_spawns_keep_clear_of_the_player() {
+        let mut app = spawner_app((1280.0, 720.0));
+        let player_position = Vec2::new(300.0, 300.0);
+        app.world.spawn((
+            Transform::from_translation(player_position.extend(0.0)),
+            Player {},
+        ));
+
+        request_enemies(&mut app, 20);
+
+        let positions = enemy_positions(&mut app);
+        assert!(!positions.is_empty());
+        for position in positions {
+            assert!(position.distance(player_position) >= ENEMY_PLAYER_CLEARANCE);
+        }
+    }
+
+    #[test]
+    fn spawn_is_skipped_when_no_spot_is_clear() {
+        // Every spot in this arena is within the clearance of its centre,
+        // where the player starts.
+        let mut app = spawner_app((300.0, 300.0));
+
+        request_enemies(&mut app, 5);
+
+        assert!(enemy_positions(&mut app).is_empty());
+    }
+
+    #[test]
+    fn aimed_spawns_head_for_the_player_start_before_the_player_exists() {
+        let mut app = spawner_app((1280.0, 720.0));
+        app.world.send_event(SpawnRequest {
+            kind: SpawnKind::Enemy(EnemySpawn {
+                aimed: true,
+                ..default()
+            }),
+            placement: Placement::At(Vec2::new(100.0, 360.0)),
+        });
+        app.update();
+
+        let enemy = app.world.query::<&Enemy>().single(&app.world).direction;
+        // Towards the middle of the arena, give or take the aim spread.
+        assert!(enemy.x > 0.0);
+        assert!(enemy.y.abs() < enemy.x);
+    }
+}
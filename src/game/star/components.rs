@@ -1,4 +1,84 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::STAR_SIZE;
+use crate::config::{StarVariantConfig, StarsConfig};
+use crate::game::spawner::components::Footprint;
+
+#[derive(Component, Default)]
+pub struct Star {
+    pub kind: StarKind,
+    // Seconds since the star spawned; drives its value decay and fade-out.
+    pub age: f32,
+}
+
+impl Star {
+    pub fn value(&self, config: &StarsConfig) -> u32 {
+        let variant = config.variant(self.kind);
+        variant
+            .value
+            .unwrap_or_else(|| config.value_at(self.age) * variant.value_multiplier)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum StarKind {
+    #[default]
+    Normal,
+    Gold,
+    Drifting,
+    Teleporting,
+}
+
+impl StarKind {
+    pub const ALL: [StarKind; 4] = [
+        StarKind::Normal,
+        StarKind::Gold,
+        StarKind::Drifting,
+        StarKind::Teleporting,
+    ];
+}
+
+#[derive(Bundle)]
+pub struct StarBundle {
+    pub sprite_bundle: SpriteBundle,
+    pub star: Star,
+    pub footprint: Footprint,
+}
+
+impl StarBundle {
+    pub fn new(
+        kind: StarKind,
+        position: Vec2,
+        variant: &StarVariantConfig,
+        texture: Handle<Image>,
+    ) -> StarBundle {
+        let (r, g, b, a) = variant.tint;
+        StarBundle {
+            sprite_bundle: SpriteBundle {
+                transform: Transform::from_translation(position.extend(0.0)),
+                texture,
+                sprite: Sprite {
+                    color: Color::rgba(r, g, b, a),
+                    ..default()
+                },
+                ..default()
+            },
+            star: Star { kind, age: 0.0 },
+            footprint: Footprint {
+                radius: STAR_SIZE / 2.0,
+            },
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct DriftingStar {
+    pub velocity: Vec2,
+}
 
 #[derive(Component)]
-pub struct Star {}
+pub struct TeleportingStar {
+    // Stars stay put for a moment after hopping so they can still be caught.
+    pub cooldown: f32,
+}
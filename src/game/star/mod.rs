@@ -7,8 +7,12 @@ mod systems;
 use resources::*;
 use systems::*;
 
+use crate::events::LevelStarted;
 use crate::AppState;
 
+use super::mode::mode_has_stars;
+use super::network::is_authority;
+use super::spawner::SpawnSystemSet;
 use super::SimulationState;
 
 pub const NUMBER_OF_STARS: usize = 10;
@@ -22,11 +26,27 @@ impl Plugin for StarPlugin {
             // .add_startup_system(spawn_stars)
             // .add_system(tick_star_spawn_timer)
             // .add_system(spawn_stars_over_time);
-            .add_system(spawn_stars.in_schedule(OnEnter(AppState::Game)))
+            .add_system(
+                spawn_stars
+                    .before(SpawnSystemSet)
+                    .in_set(OnUpdate(AppState::Game))
+                    .run_if(is_authority)
+                    .run_if(mode_has_stars)
+                    .run_if(on_event::<LevelStarted>()),
+            )
             .add_systems(
-                (tick_star_spawn_timer, spawn_stars_over_time)
+                (
+                    tick_star_spawn_timer,
+                    spawn_stars_over_time
+                        .before(SpawnSystemSet)
+                        .run_if(mode_has_stars),
+                    age_stars,
+                    drift_stars,
+                    teleport_stars,
+                )
                     .in_set(OnUpdate(AppState::Game))
-                    .in_set(OnUpdate(SimulationState::Running)),
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .distributive_run_if(is_authority),
             )
             .add_system(despawn_stars.in_schedule(OnExit(AppState::Game)));
     }
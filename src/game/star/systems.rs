@@ -1,30 +1,35 @@
+use std::f32::consts::TAU;
+
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 use rand::prelude::*;
 
-use super::components::Star;
+use super::components::*;
 use super::resources::*;
-use super::NUMBER_OF_STARS;
+use super::{NUMBER_OF_STARS, STAR_SIZE};
+use crate::config::GameConfig;
+use crate::events::SpawnRequest;
+use crate::game::level::resources::ActiveLevel;
+use crate::game::network::components::RemotePlayer;
+use crate::game::player::components::Player;
+use crate::game::resources::GameRng;
+use crate::game::spawner::components::{Placement, SpawnKind};
 
-pub fn spawn_stars(
-    mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-) {
-    let window = window_query.get_single().unwrap();
+type PlayerFilter = (Or<(With<Player>, With<RemotePlayer>)>, Without<Star>);
 
-    for _ in 0..NUMBER_OF_STARS {
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
+// One entry in the weighted spawn table.
+#[derive(Clone, Copy)]
+enum StarSpawn {
+    Single(StarKind),
+    Cluster,
+}
 
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/star.png"),
-                ..default()
-            },
-            Star {},
-        ));
+pub fn spawn_stars(mut spawn_request_event_writer: EventWriter<SpawnRequest>) {
+    for _ in 0..NUMBER_OF_STARS {
+        spawn_request_event_writer.send(SpawnRequest {
+            kind: SpawnKind::Star(StarKind::Normal),
+            placement: Placement::StarRegion,
+        });
     }
 }
 
@@ -39,23 +44,237 @@ pub fn tick_star_spawn_timer(mut star_spawn_timer: ResMut<StarSpawnTimer>, time:
 }
 
 pub fn spawn_stars_over_time(
+    star_spawn_timer: Res<StarSpawnTimer>,
+    star_query: Query<(), With<Star>>,
+    active_level: Res<ActiveLevel>,
+    mut game_rng: ResMut<GameRng>,
+    game_config: Res<GameConfig>,
+    mut spawn_request_event_writer: EventWriter<SpawnRequest>,
+) {
+    let stars_config = &game_config.stars;
+    let star_count = star_query.iter().count();
+    if !star_spawn_timer.timer.finished() || star_count >= stars_config.max_on_field {
+        return;
+    }
+
+    let spawn_table = [
+        (
+            StarSpawn::Single(StarKind::Normal),
+            stars_config.normal.weight,
+        ),
+        (StarSpawn::Single(StarKind::Gold), stars_config.gold.weight),
+        (
+            StarSpawn::Single(StarKind::Drifting),
+            stars_config.drifting.weight,
+        ),
+        (
+            StarSpawn::Single(StarKind::Teleporting),
+            stars_config.teleporting.weight,
+        ),
+        (StarSpawn::Cluster, stars_config.cluster.weight),
+    ];
+    let rng = &mut game_rng.rng;
+    // Fails only when every weight is zero, which switches spawning off.
+    let Ok((star_spawn, _)) = spawn_table.choose_weighted(rng, |(_, weight)| *weight) else {
+        return;
+    };
+
+    match *star_spawn {
+        StarSpawn::Single(kind) => spawn_request_event_writer.send(SpawnRequest {
+            kind: SpawnKind::Star(kind),
+            placement: Placement::StarRegion,
+        }),
+        StarSpawn::Cluster => {
+            let centre = active_level.random_star_position(rng);
+            let count = stars_config
+                .cluster
+                .count
+                .min(stars_config.max_on_field - star_count);
+            for _ in 0..count {
+                spawn_request_event_writer.send(SpawnRequest {
+                    kind: SpawnKind::Star(StarKind::Normal),
+                    placement: Placement::Around {
+                        centre,
+                        radius: stars_config.cluster.radius,
+                    },
+                });
+            }
+        }
+    }
+}
+
+pub fn age_stars(
     mut commands: Commands,
+    mut star_query: Query<(Entity, &mut Star, &mut Sprite)>,
+    game_config: Res<GameConfig>,
+    time: Res<Time>,
+) {
+    for (star_entity, mut star, mut sprite) in star_query.iter_mut() {
+        star.age += time.delta_seconds();
+        if star.age >= game_config.stars.lifetime {
+            commands.entity(star_entity).despawn();
+        } else {
+            let tint_alpha = game_config.stars.variant(star.kind).tint.3;
+            sprite
+                .color
+                .set_a(tint_alpha * game_config.stars.alpha_at(star.age));
+        }
+    }
+}
+
+pub fn drift_stars(
+    mut star_query: Query<(&mut Transform, &mut DriftingStar)>,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    star_spawn_timer: Res<StarSpawnTimer>,
+    time: Res<Time>,
 ) {
-    if star_spawn_timer.timer.finished() {
-        let window = window_query.get_single().unwrap();
-        let random_x = random::<f32>() * window.width();
-        let random_y = random::<f32>() * window.height();
-
-        commands.spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(random_x, random_y, 0.0),
-                texture: asset_server.load("sprites/star.png"),
+    let window = window_query.get_single().unwrap();
+    let half_star_size = STAR_SIZE / 2.0;
+    let x_max = window.width() - half_star_size;
+    let y_max = window.height() - half_star_size;
+
+    for (mut transform, mut drifting_star) in star_query.iter_mut() {
+        transform.translation += drifting_star.velocity.extend(0.0) * time.delta_seconds();
+
+        let translation = transform.translation;
+        if translation.x < half_star_size || translation.x > x_max {
+            drifting_star.velocity.x *= -1.0;
+        }
+        if translation.y < half_star_size || translation.y > y_max {
+            drifting_star.velocity.y *= -1.0;
+        }
+        transform.translation.x = translation.x.clamp(half_star_size, x_max);
+        transform.translation.y = translation.y.clamp(half_star_size, y_max);
+    }
+}
+
+pub fn teleport_stars(
+    mut star_query: Query<(&mut Transform, &mut TeleportingStar), With<Star>>,
+    player_query: Query<&Transform, PlayerFilter>,
+    active_level: Res<ActiveLevel>,
+    mut game_rng: ResMut<GameRng>,
+    game_config: Res<GameConfig>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut teleporting_star) in star_query.iter_mut() {
+        teleporting_star.cooldown -= time.delta_seconds();
+        if teleporting_star.cooldown > 0.0 {
+            continue;
+        }
+
+        let stars_config = &game_config.stars;
+        let approached = player_query.iter().any(|player_transform| {
+            player_transform.translation.distance(transform.translation)
+                < stars_config.teleport_radius
+        });
+        if approached {
+            let rng = &mut game_rng.rng;
+            let distance = rng.gen_range(
+                stars_config.teleport_radius
+                    ..=stars_config
+                        .teleport_distance
+                        .max(stars_config.teleport_radius),
+            );
+            let half_star_size = STAR_SIZE / 2.0;
+            let position = (transform.translation.truncate()
+                + Vec2::from_angle(rng.gen::<f32>() * TAU) * distance)
+                .clamp(
+                    Vec2::splat(half_star_size),
+                    (active_level.world_size() - half_star_size).max(Vec2::splat(half_star_size)),
+                );
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+            teleporting_star.cooldown = stars_config.teleport_cooldown;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use rand::rngs::StdRng;
+
+    use super::*;
+    use crate::game::player::{PLAYER_SIZE, PLAYER_SPEED};
+
+    const FRAME_SECONDS: f32 = 1.0 / 60.0;
+
+    // Runs straight at the star at normal speed and picks it up the way
+    // `player_hit_star` does.
+    fn chase_star(
+        mut commands: Commands,
+        mut player_query: Query<&mut Transform, (With<Player>, Without<Star>)>,
+        star_query: Query<(Entity, &Transform), With<Star>>,
+    ) {
+        let mut player_transform = player_query.single_mut();
+        for (star_entity, star_transform) in star_query.iter() {
+            let offset = star_transform.translation - player_transform.translation;
+            player_transform.translation += offset.clamp_length_max(PLAYER_SPEED * FRAME_SECONDS);
+            let distance = player_transform
+                .translation
+                .distance(star_transform.translation);
+            if distance < PLAYER_SIZE / 2.0 + STAR_SIZE / 2.0 {
+                commands.entity(star_entity).despawn();
+            }
+        }
+    }
+
+    // Chases a teleporting star from across the arena and returns how many
+    // times it hopped before being caught, if it was caught within ten seconds.
+    fn hops_before_caught(seed: u64) -> Option<u32> {
+        let mut app = App::new();
+        app.insert_resource(GameConfig::default())
+            .insert_resource(ActiveLevel {
+                scale: Vec2::ONE,
                 ..default()
-            },
-            Star {},
-        ));
+            })
+            .insert_resource(GameRng {
+                rng: StdRng::seed_from_u64(seed),
+            })
+            .insert_resource(Time::default())
+            // The star gets to hop before the player reaches it each frame.
+            .add_systems((teleport_stars, chase_star).chain());
+
+        app.world
+            .spawn((Transform::from_xyz(100.0, 100.0, 0.0), Player {}));
+        let mut star_position = Vec3::new(640.0, 360.0, 0.0);
+        let star_entity = app
+            .world
+            .spawn((
+                Transform::from_translation(star_position),
+                Star {
+                    kind: StarKind::Teleporting,
+                    age: 0.0,
+                },
+                TeleportingStar { cooldown: 0.0 },
+            ))
+            .id();
+
+        let start = Instant::now();
+        let mut hops = 0;
+        for frame in 0..600 {
+            app.world
+                .resource_mut::<Time>()
+                .update_with_instant(start + Duration::from_secs_f32(frame as f32 * FRAME_SECONDS));
+            app.update();
+
+            let Some(star_transform) = app.world.get::<Transform>(star_entity) else {
+                return Some(hops);
+            };
+            if star_transform.translation.distance(star_position) > 1.0 {
+                hops += 1;
+                star_position = star_transform.translation;
+            }
+        }
+        None
+    }
+
+    #[test]
+    fn teleporting_stars_can_be_caught() {
+        // It always gets away once, but never gets a second hop in before the
+        // player catches up with it.
+        for seed in 0..20 {
+            assert_eq!(hops_before_caught(seed), Some(1), "seed {}", seed);
+        }
     }
 }
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::systems::{
+    layout::{despawn_loading_screen, spawn_loading_screen},
+    progress::track_loading_progress,
+};
+
+mod components;
+mod styles;
+mod systems;
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(spawn_loading_screen.in_schedule(OnEnter(AppState::Loading)))
+            .add_system(track_loading_progress.in_set(OnUpdate(AppState::Loading)))
+            .add_system(despawn_loading_screen.in_schedule(OnExit(AppState::Loading)));
+    }
+}
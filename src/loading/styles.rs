@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+pub const BAR_BACKGROUND_COLOUR: Color = Color::rgb(0.1, 0.1, 0.1);
+pub const BAR_FILL_COLOUR: Color = Color::rgb(0.25, 0.45, 0.85);
+
+pub const BAR_STYLE: Style = Style {
+    size: Size::new(Val::Px(480.), Val::Px(24.)),
+    ..Style::DEFAULT
+};
+
+pub fn get_bar_fill_style(progress: f32) -> Style {
+    Style {
+        size: Size::new(Val::Percent(progress * 100.0), Val::Percent(100.0)),
+        ..Style::DEFAULT
+    }
+}
+
+// Drawn as soon as the font itself arrives, which is usually first.
+pub fn get_loading_text_style(game_assets: &GameAssets) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 32.,
+        color: Color::WHITE,
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets::GameAssets,
+    loading::{
+        components::{LoadingScreen, ProgressBarFill, ProgressText},
+        styles::{
+            get_bar_fill_style, get_loading_text_style, BAR_BACKGROUND_COLOUR, BAR_FILL_COLOUR,
+            BAR_STYLE,
+        },
+    },
+    main_menu::styles::MAIN_MENU_STYLE,
+};
+
+pub fn spawn_loading_screen(mut commands: Commands, game_assets: Res<GameAssets>) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: MAIN_MENU_STYLE,
+                ..default()
+            },
+            LoadingScreen {},
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle {
+                    text: Text::from_section("Loading...", get_loading_text_style(&game_assets)),
+                    ..default()
+                },
+                ProgressText {},
+            ));
+            parent
+                .spawn(NodeBundle {
+                    style: BAR_STYLE,
+                    background_color: BAR_BACKGROUND_COLOUR.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: get_bar_fill_style(0.0),
+                            background_color: BAR_FILL_COLOUR.into(),
+                            ..default()
+                        },
+                        ProgressBarFill {},
+                    ));
+                });
+        });
+}
+
+pub fn despawn_loading_screen(
+    mut commands: Commands,
+    loading_screen_query: Query<Entity, With<LoadingScreen>>,
+) {
+    if let Ok(loading_screen_entity) = loading_screen_query.get_single() {
+        commands.entity(loading_screen_entity).despawn_recursive();
+    }
+}
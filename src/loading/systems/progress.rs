@@ -0,0 +1,63 @@
+use bevy::asset::{HandleId, LoadState};
+use bevy::prelude::*;
+
+use crate::{
+    assets::GameAssets,
+    loading::{
+        components::{ProgressBarFill, ProgressText},
+        styles::get_bar_fill_style,
+    },
+    AppState,
+};
+
+pub fn track_loading_progress(
+    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAssets>,
+    mut bar_query: Query<&mut Style, With<ProgressBarFill>>,
+    mut text_query: Query<&mut Text, With<ProgressText>>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    let required = game_assets.required();
+    let optional = game_assets.optional();
+    // Failed loads are finished too; they're reported below rather than waited on.
+    let is_finished = |handle: &HandleId| {
+        matches!(
+            asset_server.get_load_state(*handle),
+            LoadState::Loaded | LoadState::Failed
+        )
+    };
+
+    let total = required.len() + optional.len();
+    let finished = required
+        .iter()
+        .chain(optional.iter())
+        .filter(|handle| is_finished(handle))
+        .count();
+
+    if let Ok(mut style) = bar_query.get_single_mut() {
+        *style = get_bar_fill_style(finished as f32 / total.max(1) as f32);
+    }
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = format!("Loading... {} / {}", finished, total);
+    }
+
+    if finished < total {
+        return;
+    }
+
+    report_missing(&asset_server, &required, "Missing asset");
+    report_missing(&asset_server, &optional, "Missing optional asset");
+    app_state_next_state.set(AppState::MainMenu);
+}
+
+fn report_missing(asset_server: &AssetServer, handles: &[HandleId], label: &str) {
+    for handle in handles {
+        if asset_server.get_load_state(*handle) == LoadState::Failed {
+            let path = asset_server.get_handle_path(*handle).map_or_else(
+                || "<unknown>".to_string(),
+                |path| path.path().display().to_string(),
+            );
+            println!("{}: assets/{}", label, path);
+        }
+    }
+}
@@ -1,32 +1,92 @@
+mod achievements;
+mod assets;
+mod audio;
+mod camera;
+mod campaign;
+mod config;
+mod editor;
 pub mod events;
 mod game;
+mod loading;
 mod main_menu;
+mod mutators;
+mod persistence;
+mod settings;
+mod stats;
 mod systems;
 
+use achievements::AchievementsPlugin;
+use assets::GameAssets;
+use audio::GameAudioPlugin;
+use camera::{CameraEffectsPlugin, HitStopSystemSet};
+use campaign::CampaignPlugin;
+use config::GameConfig;
+use editor::EditorPlugin;
+use game::network::relay::run_relay;
+use game::network::resources::NetworkRole;
+use game::particles::EmitParticlesSystemSet;
 use game::GamePlugin;
+use loading::LoadingPlugin;
 use main_menu::MainMenuPlugin;
+use mutators::MutatorsPlugin;
+use settings::SettingsPlugin;
+use stats::StatsPlugin;
 use systems::*;
 
 use bevy::prelude::*;
 
 fn main() {
+    let network_role = NetworkRole::from_args(std::env::args().skip(1));
+
+    // A standalone relay doesn't need a window or any of the game.
+    if let NetworkRole::Relay { relay_address } = network_role {
+        let socket = std::net::UdpSocket::bind(relay_address).expect("Failed to bind relay");
+        run_relay(socket);
+        return;
+    }
+
     App::new()
         .add_plugins(DefaultPlugins)
+        .insert_resource(network_role)
+        .insert_resource(GameConfig::load())
+        .init_resource::<GameAssets>()
         .add_state::<AppState>()
+        .add_plugin(LoadingPlugin)
+        .add_plugin(SettingsPlugin)
         .add_plugin(MainMenuPlugin)
         .add_plugin(GamePlugin)
+        .add_plugin(GameAudioPlugin)
+        .add_plugin(CameraEffectsPlugin)
+        .add_plugin(AchievementsPlugin)
+        .add_plugin(StatsPlugin)
+        .add_plugin(EditorPlugin)
+        .add_plugin(CampaignPlugin)
+        .add_plugin(MutatorsPlugin)
         .add_startup_system(spawn_camera)
-        .add_system(transition_to_game_state)
-        .add_system(transition_to_menu_state)
+        .add_system(recenter_camera)
+        // The shortcuts wait until everything has loaded.
+        .add_system(transition_to_game_state.run_if(not(in_state(AppState::Loading))))
+        .add_system(transition_to_menu_state.run_if(not(in_state(AppState::Loading))))
         .add_system(exit_game)
-        .add_system(handle_game_over)
+        .add_system(
+            handle_game_over
+                .after(HitStopSystemSet)
+                .after(EmitParticlesSystemSet),
+        )
         .run();
 }
 
 #[derive(States, Clone, Copy, Eq, PartialEq, Hash, Default, Debug)]
 pub enum AppState {
-    MainMenu,
     #[default]
+    Loading,
+    MainMenu,
     Game,
     GameOver,
+    Settings,
+    Achievements,
+    Stats,
+    Editor,
+    LevelSelect,
+    Mutators,
 }
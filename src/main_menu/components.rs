@@ -1,8 +1,26 @@
-use bevy::prelude::Component;
+use bevy::prelude::*;
+
+// The hover and click colours of a menu button with the `M` marker.
+pub type ButtonQuery<'w, 's, M> = Query<
+    'w,
+    's,
+    (&'static Interaction, &'static mut BackgroundColor),
+    (Changed<Interaction>, With<M>),
+>;
 
 #[derive(Component)]
 pub struct MainMenu {}
 #[derive(Component)]
 pub struct PlayButton {}
 #[derive(Component)]
+pub struct DailyChallengeButton {}
+#[derive(Component)]
+pub struct SettingsButton {}
+#[derive(Component)]
+pub struct AchievementsButton {}
+#[derive(Component)]
+pub struct StatsButton {}
+#[derive(Component)]
+pub struct EditorButton {}
+#[derive(Component)]
 pub struct QuitButton {}
@@ -3,11 +3,15 @@ use bevy::prelude::*;
 use crate::AppState;
 
 use self::systems::{
-    interactions::{interact_with_play_button, interact_with_quit_button},
+    interactions::{
+        interact_with_achievements_button, interact_with_daily_challenge_button,
+        interact_with_editor_button, interact_with_play_button, interact_with_quit_button,
+        interact_with_settings_button, interact_with_stats_button,
+    },
     layout::{despawn_main_menu, spawn_main_menu},
 };
-mod components;
-mod styles;
+pub mod components;
+pub mod styles;
 mod systems;
 
 pub struct MainMenuPlugin;
@@ -15,7 +19,15 @@ pub struct MainMenuPlugin;
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_system(spawn_main_menu.in_schedule(OnEnter(AppState::MainMenu)))
-            .add_systems((interact_with_play_button, interact_with_quit_button))
+            .add_systems((
+                interact_with_play_button,
+                interact_with_daily_challenge_button,
+                interact_with_settings_button,
+                interact_with_achievements_button,
+                interact_with_stats_button,
+                interact_with_editor_button,
+                interact_with_quit_button,
+            ))
             .add_system(despawn_main_menu.in_schedule(OnExit(AppState::MainMenu)));
     }
 }
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::assets::GameAssets;
+
 pub const NORMAL_BUTTON_COLOUR: Color = Color::rgb(0.15, 0.15, 0.15);
 pub const HOVERED_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
 pub const PRESSED_BUTTON_COLOR: Color = Color::rgb(0.35, 0.35, 0.35);
@@ -11,6 +13,13 @@ pub const BUTTON_STYLE: Style = Style {
     ..Style::DEFAULT
 };
 
+// Buttons side by side inside a menu column.
+pub const BUTTON_ROW_STYLE: Style = Style {
+    flex_direction: FlexDirection::Row,
+    gap: Size::new(Val::Px(8.), Val::Px(8.)),
+    ..Style::DEFAULT
+};
+
 pub const IMAGE_STYLE: Style = Style {
     size: Size::new(Val::Px(64.), Val::Px(64.)),
     margin: UiRect::new(Val::Px(8.), Val::Px(8.), Val::Px(8.), Val::Px(8.)),
@@ -34,17 +43,17 @@ pub const MAIN_MENU_STYLE: Style = Style {
     ..Style::DEFAULT
 };
 
-pub fn get_button_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+pub fn get_button_text_style(game_assets: &GameAssets) -> TextStyle {
     TextStyle {
-        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font: game_assets.font.clone(),
         font_size: 32.,
         color: Color::WHITE,
     }
 }
 
-pub fn get_title_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+pub fn get_title_text_style(game_assets: &GameAssets) -> TextStyle {
     TextStyle {
-        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font: game_assets.font.clone(),
         font_size: 64.,
         color: Color::WHITE,
     }
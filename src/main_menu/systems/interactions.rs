@@ -1,24 +1,54 @@
 use bevy::{app::AppExit, prelude::*};
 
 use crate::{
+    config::GameConfig,
+    game::{
+        daily::resources::DailyChallenge, level::resources::CurrentLevel, mode::resources::GameMode,
+    },
     main_menu::{
-        components::{PlayButton, QuitButton},
+        components::{
+            AchievementsButton, ButtonQuery, DailyChallengeButton, EditorButton, PlayButton,
+            QuitButton, SettingsButton, StatsButton,
+        },
         styles::{HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOUR, PRESSED_BUTTON_COLOR},
     },
     AppState,
 };
 
 pub fn interact_with_play_button(
-    mut button_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<PlayButton>),
-    >,
+    mut button_query: ButtonQuery<PlayButton>,
     mut app_state_next_state: ResMut<NextState<AppState>>,
 ) {
     if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
         match *interaction {
             Interaction::Clicked => {
                 *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::LevelSelect)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+// Plays the configured level with today's seed and modifiers.
+pub fn interact_with_daily_challenge_button(
+    mut button_query: ButtonQuery<DailyChallengeButton>,
+    mut daily_challenge: ResMut<DailyChallenge>,
+    mut game_mode: ResMut<GameMode>,
+    mut current_level: ResMut<CurrentLevel>,
+    asset_server: Res<AssetServer>,
+    game_config: Res<GameConfig>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                // The game may have been left open past midnight.
+                *daily_challenge = DailyChallenge::today();
+                *game_mode = GameMode::DailyChallenge;
+                *current_level = CurrentLevel::from_config(&asset_server, &game_config);
                 app_state_next_state.set(AppState::Game)
             }
             Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
@@ -27,11 +57,72 @@ pub fn interact_with_play_button(
     }
 }
 
+pub fn interact_with_settings_button(
+    mut button_query: ButtonQuery<SettingsButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::Settings)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_achievements_button(
+    mut button_query: ButtonQuery<AchievementsButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::Achievements)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_stats_button(
+    mut button_query: ButtonQuery<StatsButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::Stats)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_editor_button(
+    mut button_query: ButtonQuery<EditorButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::Editor)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
 pub fn interact_with_quit_button(
-    mut button_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<QuitButton>),
-    >,
+    mut button_query: ButtonQuery<QuitButton>,
     mut app_exit_event_writer: EventWriter<AppExit>,
 ) {
     if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
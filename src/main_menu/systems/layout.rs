@@ -1,18 +1,23 @@
 use bevy::prelude::*;
 
 use crate::{
+    assets::GameAssets,
+    game::enemy::components::EnemyKind,
     main,
     main_menu::{
-        components::{MainMenu, PlayButton, QuitButton},
+        components::{
+            AchievementsButton, DailyChallengeButton, EditorButton, MainMenu, PlayButton,
+            QuitButton, SettingsButton, StatsButton,
+        },
         styles::{
-            get_button_text_style, get_title_text_style, BUTTON_STYLE, IMAGE_STYLE,
-            MAIN_MENU_STYLE, NORMAL_BUTTON_COLOUR, TITLE_STYLE,
+            get_button_text_style, get_title_text_style, BUTTON_ROW_STYLE, BUTTON_STYLE,
+            IMAGE_STYLE, MAIN_MENU_STYLE, NORMAL_BUTTON_COLOUR, TITLE_STYLE,
         },
     },
 };
 
-pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let main_menu_entity = build_main_menu(&mut commands, &asset_server);
+pub fn spawn_main_menu(mut commands: Commands, game_assets: Res<GameAssets>) {
+    let main_menu_entity = build_main_menu(&mut commands, &game_assets);
 }
 
 pub fn despawn_main_menu(mut commands: Commands, main_menu_query: Query<Entity, With<MainMenu>>) {
@@ -21,7 +26,7 @@ pub fn despawn_main_menu(mut commands: Commands, main_menu_query: Query<Entity,
     }
 }
 
-pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>) -> Entity {
+pub fn build_main_menu(commands: &mut Commands, game_assets: &GameAssets) -> Entity {
     let main_menu_entity = commands
         .spawn((
             NodeBundle {
@@ -42,7 +47,7 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
                     // Image 1
                     parent.spawn(ImageBundle {
                         style: IMAGE_STYLE,
-                        image: asset_server.load("sprites/ball_blue_large.png").into(),
+                        image: game_assets.player.clone().into(),
                         ..default()
                     });
                     // Text
@@ -50,7 +55,7 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
                         text: Text {
                             sections: vec![TextSection::new(
                                 "Bevy Ball Game",
-                                get_title_text_style(&asset_server),
+                                get_title_text_style(&game_assets),
                             )],
                             alignment: TextAlignment::Center,
                             ..default()
@@ -60,11 +65,134 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
                     // Image 2
                     parent.spawn(ImageBundle {
                         style: IMAGE_STYLE,
-                        image: asset_server.load("sprites/ball_red_large.png").into(),
+                        image: game_assets.enemy(EnemyKind::Bouncer).into(),
+                        ..default()
+                    });
+                });
+            // Play and Daily Challenge
+            parent
+                .spawn(NodeBundle {
+                    style: BUTTON_ROW_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    // Play
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: BUTTON_STYLE,
+                                background_color: NORMAL_BUTTON_COLOUR.into(),
+                                ..default()
+                            },
+                            PlayButton {},
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle {
+                                text: Text {
+                                    sections: vec![TextSection::new(
+                                        "Play",
+                                        get_button_text_style(&game_assets),
+                                    )],
+                                    alignment: TextAlignment::Center,
+                                    ..default()
+                                },
+                                ..default()
+                            });
+                        });
+                    // Daily Challenge
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: BUTTON_STYLE,
+                                background_color: NORMAL_BUTTON_COLOUR.into(),
+                                ..default()
+                            },
+                            DailyChallengeButton {},
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle {
+                                text: Text {
+                                    sections: vec![TextSection::new(
+                                        "Daily",
+                                        get_button_text_style(game_assets),
+                                    )],
+                                    alignment: TextAlignment::Center,
+                                    ..default()
+                                },
+                                ..default()
+                            });
+                        });
+                });
+            // Settings
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    SettingsButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new(
+                                "Settings",
+                                get_button_text_style(game_assets),
+                            )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    });
+                });
+            // Achievements
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    AchievementsButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new(
+                                "Achievements",
+                                get_button_text_style(game_assets),
+                            )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    });
+                });
+            // Stats
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    StatsButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new(
+                                "Stats",
+                                get_button_text_style(game_assets),
+                            )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
                         ..default()
                     });
                 });
-            // Play
+            // Editor
             parent
                 .spawn((
                     ButtonBundle {
@@ -72,14 +200,14 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
                         background_color: NORMAL_BUTTON_COLOUR.into(),
                         ..default()
                     },
-                    PlayButton {},
+                    EditorButton {},
                 ))
                 .with_children(|parent| {
                     parent.spawn(TextBundle {
                         text: Text {
                             sections: vec![TextSection::new(
-                                "Play",
-                                get_button_text_style(&asset_server),
+                                "Editor",
+                                get_button_text_style(game_assets),
                             )],
                             alignment: TextAlignment::Center,
                             ..default()
@@ -103,7 +231,7 @@ pub fn build_main_menu(commands: &mut Commands, asset_server: &Res<AssetServer>)
                             sections: vec![TextSection::new(
                                 "Quit",
                                 TextStyle {
-                                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                    font: game_assets.font.clone(),
                                     font_size: 32.,
                                     color: Color::WHITE,
                                 },
@@ -0,0 +1,21 @@
+use bevy::prelude::Component;
+
+use crate::game::modifiers::resources::Modifier;
+
+#[derive(Component)]
+pub struct MutatorsMenu {}
+#[derive(Component)]
+pub struct StartButton {}
+#[derive(Component)]
+pub struct BackButton {}
+#[derive(Component)]
+pub struct MultiplierText {}
+
+#[derive(Component)]
+pub struct MutatorButton {
+    pub modifier: Modifier,
+}
+#[derive(Component)]
+pub struct MutatorText {
+    pub modifier: Modifier,
+}
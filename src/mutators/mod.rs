@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::systems::{
+    interactions::{
+        interact_with_back_button, interact_with_mutator_buttons, interact_with_start_button,
+        update_mutators_menu,
+    },
+    layout::{despawn_mutators_menu, spawn_mutators_menu},
+};
+use resources::MutatorSelection;
+
+mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+// The pre-game screen between picking a free-play mode and playing it.
+pub struct MutatorsPlugin;
+
+impl Plugin for MutatorsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MutatorSelection>()
+            .add_system(spawn_mutators_menu.in_schedule(OnEnter(AppState::Mutators)))
+            .add_systems(
+                (
+                    interact_with_mutator_buttons,
+                    interact_with_start_button,
+                    interact_with_back_button,
+                    update_mutators_menu,
+                )
+                    .in_set(OnUpdate(AppState::Mutators)),
+            )
+            .add_system(despawn_mutators_menu.in_schedule(OnExit(AppState::Mutators)));
+    }
+}
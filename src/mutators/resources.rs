@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::game::modifiers::resources::Modifier;
+
+// What is switched on in the mutators screen. Kept between runs so the same
+// set can be replayed, and copied into `RunModifiers` when a run starts.
+#[derive(Resource, Default)]
+pub struct MutatorSelection {
+    pub active: Vec<Modifier>,
+}
+
+impl MutatorSelection {
+    pub fn is_on(&self, modifier: Modifier) -> bool {
+        self.active.contains(&modifier)
+    }
+
+    pub fn toggle(&mut self, modifier: Modifier) {
+        if self.is_on(modifier) {
+            self.active.retain(|active| *active != modifier);
+        } else {
+            self.active.push(modifier);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+pub const ROW_STYLE: Style = Style {
+    flex_direction: FlexDirection::Row,
+    gap: Size::new(Val::Px(8.), Val::Px(8.)),
+    ..Style::DEFAULT
+};
+
+pub const MUTATOR_BUTTON_STYLE: Style = Style {
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    size: Size::new(Val::Px(400.), Val::Px(56.)),
+    ..Style::DEFAULT
+};
+
+pub fn get_mutator_text_style(game_assets: &GameAssets) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 22.,
+        color: Color::WHITE,
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::modifiers::resources::RunModifiers,
+    main_menu::{
+        components::ButtonQuery,
+        styles::{HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOUR, PRESSED_BUTTON_COLOR},
+    },
+    mutators::{
+        components::{BackButton, MultiplierText, MutatorButton, MutatorText, StartButton},
+        resources::MutatorSelection,
+        systems::layout::{get_multiplier_label, get_mutator_label},
+    },
+    AppState,
+};
+
+pub fn interact_with_mutator_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &MutatorButton),
+        Changed<Interaction>,
+    >,
+    mut mutator_selection: ResMut<MutatorSelection>,
+) {
+    for (interaction, mut background_colour, mutator_button) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                mutator_selection.toggle(mutator_button.modifier);
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_start_button(
+    mut button_query: ButtonQuery<StartButton>,
+    mutator_selection: Res<MutatorSelection>,
+    mut run_modifiers: ResMut<RunModifiers>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                run_modifiers.active = mutator_selection.active.clone();
+                app_state_next_state.set(AppState::Game)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: ButtonQuery<BackButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::LevelSelect)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn update_mutators_menu(
+    mutator_selection: Res<MutatorSelection>,
+    mut text_query: Query<(&mut Text, &MutatorText), Without<MultiplierText>>,
+    mut multiplier_text_query: Query<&mut Text, With<MultiplierText>>,
+) {
+    if mutator_selection.is_changed() {
+        for (mut text, mutator_text) in text_query.iter_mut() {
+            text.sections[0].value = get_mutator_label(mutator_text.modifier, &mutator_selection);
+        }
+        if let Ok(mut text) = multiplier_text_query.get_single_mut() {
+            text.sections[0].value = get_multiplier_label(&mutator_selection);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets::GameAssets,
+    game::{
+        mode::resources::GameMode,
+        modifiers::resources::{Modifier, RunModifiers},
+    },
+    main_menu::styles::{
+        get_button_text_style, get_title_text_style, BUTTON_ROW_STYLE, BUTTON_STYLE,
+        MAIN_MENU_STYLE, NORMAL_BUTTON_COLOUR,
+    },
+    mutators::{
+        components::{
+            BackButton, MultiplierText, MutatorButton, MutatorText, MutatorsMenu, StartButton,
+        },
+        resources::MutatorSelection,
+        styles::{get_mutator_text_style, MUTATOR_BUTTON_STYLE, ROW_STYLE},
+    },
+};
+
+pub fn spawn_mutators_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    mutator_selection: Res<MutatorSelection>,
+    game_mode: Res<GameMode>,
+) {
+    build_mutators_menu(&mut commands, &game_assets, &mutator_selection, *game_mode);
+}
+
+pub fn despawn_mutators_menu(
+    mut commands: Commands,
+    mutators_menu_query: Query<Entity, With<MutatorsMenu>>,
+) {
+    if let Ok(mutators_menu_entity) = mutators_menu_query.get_single() {
+        commands.entity(mutators_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn build_mutators_menu(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    mutator_selection: &MutatorSelection,
+    game_mode: GameMode,
+) -> Entity {
+    commands
+        .spawn((
+            NodeBundle {
+                style: MAIN_MENU_STYLE,
+                ..default()
+            },
+            MutatorsMenu {},
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn(TextBundle::from_section(
+                game_mode.name(),
+                get_title_text_style(game_assets),
+            ));
+            // Mutators, two to a row
+            for modifiers in Modifier::ALL.chunks(2) {
+                parent
+                    .spawn(NodeBundle {
+                        style: ROW_STYLE,
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for modifier in modifiers.iter().copied() {
+                            parent
+                                .spawn((
+                                    ButtonBundle {
+                                        style: MUTATOR_BUTTON_STYLE,
+                                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                                        ..default()
+                                    },
+                                    MutatorButton { modifier },
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(
+                                            get_mutator_label(modifier, mutator_selection),
+                                            get_mutator_text_style(game_assets),
+                                        ),
+                                        MutatorText { modifier },
+                                    ));
+                                });
+                        }
+                    });
+            }
+            // Multiplier
+            parent.spawn((
+                TextBundle::from_section(
+                    get_multiplier_label(mutator_selection),
+                    get_button_text_style(game_assets),
+                ),
+                MultiplierText {},
+            ));
+            // Back and Start
+            parent
+                .spawn(NodeBundle {
+                    style: BUTTON_ROW_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: BUTTON_STYLE,
+                                background_color: NORMAL_BUTTON_COLOUR.into(),
+                                ..default()
+                            },
+                            BackButton {},
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Back",
+                                get_button_text_style(game_assets),
+                            ));
+                        });
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: BUTTON_STYLE,
+                                background_color: NORMAL_BUTTON_COLOUR.into(),
+                                ..default()
+                            },
+                            StartButton {},
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Start",
+                                get_button_text_style(game_assets),
+                            ));
+                        });
+                });
+        })
+        .id()
+}
+
+pub fn get_mutator_label(modifier: Modifier, mutator_selection: &MutatorSelection) -> String {
+    let state = if mutator_selection.is_on(modifier) {
+        "On"
+    } else {
+        "Off"
+    };
+    format!(
+        "{} (x{:.2}): {}",
+        modifier.name(),
+        modifier.score_multiplier(),
+        state
+    )
+}
+
+pub fn get_multiplier_label(mutator_selection: &MutatorSelection) -> String {
+    let run_modifiers = RunModifiers {
+        active: mutator_selection.active.clone(),
+    };
+    format!("Score x{:.2}", run_modifiers.score_multiplier())
+}
@@ -0,0 +1,43 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+// Everything we write to disk lives in here, next to the executable's working
+// directory, as RON so it can be hand edited.
+pub const SAVE_DIRECTORY: &str = "saves";
+
+fn save_path(file_name: &str) -> PathBuf {
+    PathBuf::from(SAVE_DIRECTORY).join(file_name)
+}
+
+// Missing or unreadable files fall back to the default rather than stopping the game.
+pub fn load_or_default<T: DeserializeOwned + Default>(file_name: &str) -> T {
+    let path = save_path(file_name);
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return T::default();
+    };
+
+    match ron::from_str(&contents) {
+        Ok(value) => value,
+        Err(error) => {
+            println!("Could not read {}: {}", path.display(), error);
+            T::default()
+        }
+    }
+}
+
+pub fn save<T: Serialize>(file_name: &str, value: &T) {
+    let path = save_path(file_name);
+    let result = fs::create_dir_all(SAVE_DIRECTORY)
+        .map_err(|error| error.to_string())
+        .and_then(|_| {
+            ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+                .map_err(|error| error.to_string())
+        })
+        .and_then(|contents| fs::write(&path, contents).map_err(|error| error.to_string()));
+
+    if let Err(error) = result {
+        println!("Could not save {}: {}", path.display(), error);
+    }
+}
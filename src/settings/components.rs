@@ -0,0 +1,30 @@
+use bevy::prelude::Component;
+
+use super::resources::VolumeChannel;
+
+#[derive(Component)]
+pub struct SettingsMenu {}
+#[derive(Component)]
+pub struct BackButton {}
+
+#[derive(Component)]
+pub struct VolumeButton {
+    pub channel: VolumeChannel,
+    pub step: f32,
+}
+#[derive(Component)]
+pub struct VolumeBarFill {
+    pub channel: VolumeChannel,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum ToggleButton {
+    Fullscreen,
+    Resolution,
+    Vsync,
+    CameraEffects,
+}
+#[derive(Component)]
+pub struct ToggleText {
+    pub toggle: ToggleButton,
+}
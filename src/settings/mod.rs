@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::AppState;
+
+use self::systems::{
+    apply::{apply_window_settings, save_settings},
+    interactions::{
+        interact_with_back_button, interact_with_toggle_buttons, interact_with_volume_buttons,
+        update_settings_menu,
+    },
+    layout::{despawn_settings_menu, spawn_settings_menu},
+};
+use resources::Settings;
+
+mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load())
+            .add_system(apply_window_settings)
+            .add_system(spawn_settings_menu.in_schedule(OnEnter(AppState::Settings)))
+            .add_systems(
+                (
+                    interact_with_volume_buttons,
+                    interact_with_toggle_buttons,
+                    interact_with_back_button,
+                    update_settings_menu,
+                )
+                    .in_set(OnUpdate(AppState::Settings)),
+            )
+            .add_systems(
+                (despawn_settings_menu, save_settings).in_schedule(OnExit(AppState::Settings)),
+            );
+    }
+}
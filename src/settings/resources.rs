@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+use bevy::window::{PresentMode, WindowMode};
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+pub const SETTINGS_FILE: &str = "settings.ron";
+pub const RESOLUTIONS: [(u32, u32); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+    pub fullscreen: bool,
+    pub resolution: (u32, u32),
+    pub vsync: bool,
+    // Screen shake, hit-stop and zoom pulses; off for players sensitive to motion.
+    pub camera_effects: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            master_volume: 1.0,
+            sfx_volume: 1.0,
+            music_volume: 0.5,
+            fullscreen: false,
+            resolution: RESOLUTIONS[0],
+            vsync: true,
+            camera_effects: true,
+        }
+    }
+}
+
+impl Settings {
+    pub fn load() -> Settings {
+        let mut settings: Settings = persistence::load_or_default(SETTINGS_FILE);
+        settings.validate();
+        settings
+    }
+
+    // The file may have been edited by hand; anything out of range is pulled
+    // back in or replaced with its default.
+    fn validate(&mut self) {
+        let defaults = Settings::default();
+        for channel in [
+            VolumeChannel::Master,
+            VolumeChannel::Sfx,
+            VolumeChannel::Music,
+        ] {
+            let volume = self.volume_mut(channel);
+            *volume = if volume.is_nan() {
+                defaults.volume(channel)
+            } else {
+                volume.clamp(0.0, 1.0)
+            };
+        }
+        if self.resolution.0 == 0 || self.resolution.1 == 0 {
+            println!("Ignoring invalid resolution {:?}", self.resolution);
+            self.resolution = defaults.resolution;
+        }
+    }
+
+    pub fn save(&self) {
+        persistence::save(SETTINGS_FILE, self);
+    }
+
+    pub fn volume(&self, channel: VolumeChannel) -> f32 {
+        match channel {
+            VolumeChannel::Master => self.master_volume,
+            VolumeChannel::Sfx => self.sfx_volume,
+            VolumeChannel::Music => self.music_volume,
+        }
+    }
+
+    pub fn volume_mut(&mut self, channel: VolumeChannel) -> &mut f32 {
+        match channel {
+            VolumeChannel::Master => &mut self.master_volume,
+            VolumeChannel::Sfx => &mut self.sfx_volume,
+            VolumeChannel::Music => &mut self.music_volume,
+        }
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        if self.fullscreen {
+            WindowMode::BorderlessFullscreen
+        } else {
+            WindowMode::Windowed
+        }
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        }
+    }
+
+    pub fn next_resolution(&mut self) {
+        let index = RESOLUTIONS
+            .iter()
+            .position(|resolution| *resolution == self.resolution)
+            .map_or(0, |index| (index + 1) % RESOLUTIONS.len());
+        self.resolution = RESOLUTIONS[index];
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeChannel {
+    Master,
+    Sfx,
+    Music,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_settings_are_left_alone() {
+        let mut settings = Settings {
+            master_volume: 0.3,
+            resolution: (1920, 1080),
+            ..default()
+        };
+        let expected = settings.clone();
+
+        settings.validate();
+        assert_eq!(settings, expected);
+    }
+
+    #[test]
+    fn out_of_range_volumes_are_pulled_back_in() {
+        let mut settings = Settings {
+            master_volume: 4.0,
+            sfx_volume: -1.0,
+            music_volume: f32::NAN,
+            ..default()
+        };
+
+        settings.validate();
+        assert_eq!(settings.master_volume, 1.0);
+        assert_eq!(settings.sfx_volume, 0.0);
+        assert_eq!(settings.music_volume, Settings::default().music_volume);
+    }
+
+    #[test]
+    fn zero_resolutions_fall_back_to_the_default() {
+        let mut settings = Settings {
+            resolution: (0, 720),
+            ..default()
+        };
+
+        settings.validate();
+        assert_eq!(settings.resolution, RESOLUTIONS[0]);
+    }
+
+    #[test]
+    fn unknown_resolutions_cycle_back_to_the_first() {
+        let mut settings = Settings {
+            resolution: (800, 600),
+            ..default()
+        };
+
+        settings.next_resolution();
+        assert_eq!(settings.resolution, RESOLUTIONS[0]);
+        settings.next_resolution();
+        assert_eq!(settings.resolution, RESOLUTIONS[1]);
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+pub const BAR_BACKGROUND_COLOUR: Color = Color::rgb(0.1, 0.1, 0.1);
+pub const BAR_FILL_COLOUR: Color = Color::rgb(0.25, 0.45, 0.85);
+
+pub const ROW_STYLE: Style = Style {
+    flex_direction: FlexDirection::Row,
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    gap: Size::new(Val::Px(12.), Val::Px(12.)),
+    size: Size::new(Val::Px(720.), Val::Px(64.)),
+    ..Style::DEFAULT
+};
+
+pub const LABEL_STYLE: Style = Style {
+    justify_content: JustifyContent::FlexStart,
+    size: Size::new(Val::Px(240.), Val::Auto),
+    ..Style::DEFAULT
+};
+
+pub const SMALL_BUTTON_STYLE: Style = Style {
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    size: Size::new(Val::Px(56.), Val::Px(56.)),
+    ..Style::DEFAULT
+};
+
+pub const WIDE_BUTTON_STYLE: Style = Style {
+    justify_content: JustifyContent::Center,
+    align_items: AlignItems::Center,
+    size: Size::new(Val::Px(280.), Val::Px(56.)),
+    ..Style::DEFAULT
+};
+
+pub const BAR_STYLE: Style = Style {
+    size: Size::new(Val::Px(240.), Val::Px(24.)),
+    ..Style::DEFAULT
+};
+
+pub fn get_bar_fill_style(volume: f32) -> Style {
+    Style {
+        size: Size::new(Val::Percent(volume * 100.0), Val::Percent(100.0)),
+        ..Style::DEFAULT
+    }
+}
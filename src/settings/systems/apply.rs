@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::settings::resources::Settings;
+
+// Runs every frame but only touches the window when the settings changed, which
+// includes the first frame after they were loaded.
+pub fn apply_window_settings(
+    settings: Res<Settings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if settings.is_changed() {
+        if let Ok(mut window) = window_query.get_single_mut() {
+            let (width, height) = settings.resolution;
+            window.mode = settings.window_mode();
+            window.present_mode = settings.present_mode();
+            window.resolution.set(width as f32, height as f32);
+        }
+    }
+}
+
+pub fn save_settings(settings: Res<Settings>) {
+    settings.save();
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+
+use crate::{
+    main_menu::{
+        components::ButtonQuery,
+        styles::{HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOUR, PRESSED_BUTTON_COLOR},
+    },
+    settings::{
+        components::{BackButton, ToggleButton, ToggleText, VolumeBarFill, VolumeButton},
+        resources::Settings,
+        styles::get_bar_fill_style,
+        systems::layout::get_toggle_label,
+    },
+    AppState,
+};
+
+pub fn interact_with_volume_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &VolumeButton),
+        Changed<Interaction>,
+    >,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut background_colour, volume_button) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                let volume = settings.volume_mut(volume_button.channel);
+                // Round so repeated steps don't drift away from tenths.
+                *volume = ((*volume + volume_button.step) * 10.0)
+                    .round()
+                    .clamp(0.0, 10.0)
+                    / 10.0;
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_toggle_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &ToggleButton),
+        Changed<Interaction>,
+    >,
+    mut settings: ResMut<Settings>,
+) {
+    for (interaction, mut background_colour, toggle_button) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                match toggle_button {
+                    ToggleButton::Fullscreen => settings.fullscreen = !settings.fullscreen,
+                    ToggleButton::Resolution => settings.next_resolution(),
+                    ToggleButton::Vsync => settings.vsync = !settings.vsync,
+                    ToggleButton::CameraEffects => {
+                        settings.camera_effects = !settings.camera_effects
+                    }
+                }
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: ButtonQuery<BackButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::MainMenu)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
+
+pub fn update_settings_menu(
+    settings: Res<Settings>,
+    mut bar_query: Query<(&mut Style, &VolumeBarFill)>,
+    mut text_query: Query<(&mut Text, &ToggleText)>,
+) {
+    if settings.is_changed() {
+        for (mut style, volume_bar_fill) in bar_query.iter_mut() {
+            *style = get_bar_fill_style(settings.volume(volume_bar_fill.channel));
+        }
+        for (mut text, toggle_text) in text_query.iter_mut() {
+            text.sections[0].value = get_toggle_label(toggle_text.toggle, &settings);
+        }
+    }
+}
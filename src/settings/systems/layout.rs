@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets::GameAssets,
+    main_menu::styles::{
+        get_button_text_style, get_title_text_style, BUTTON_STYLE, MAIN_MENU_STYLE,
+        NORMAL_BUTTON_COLOUR,
+    },
+    settings::{
+        components::{
+            BackButton, SettingsMenu, ToggleButton, ToggleText, VolumeBarFill, VolumeButton,
+        },
+        resources::{Settings, VolumeChannel},
+        styles::{
+            get_bar_fill_style, BAR_BACKGROUND_COLOUR, BAR_FILL_COLOUR, BAR_STYLE, LABEL_STYLE,
+            ROW_STYLE, SMALL_BUTTON_STYLE, WIDE_BUTTON_STYLE,
+        },
+    },
+};
+
+pub const VOLUME_STEP: f32 = 0.1;
+
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    settings: Res<Settings>,
+) {
+    build_settings_menu(&mut commands, &game_assets, &settings);
+}
+
+pub fn despawn_settings_menu(
+    mut commands: Commands,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+) {
+    if let Ok(settings_menu_entity) = settings_menu_query.get_single() {
+        commands.entity(settings_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn build_settings_menu(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    settings: &Settings,
+) -> Entity {
+    commands
+        .spawn((
+            NodeBundle {
+                style: MAIN_MENU_STYLE,
+                ..default()
+            },
+            SettingsMenu {},
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn(TextBundle {
+                text: Text::from_section("Settings", get_title_text_style(game_assets)),
+                ..default()
+            });
+            // Volume sliders
+            spawn_volume_row(
+                parent,
+                game_assets,
+                settings,
+                "Master",
+                VolumeChannel::Master,
+            );
+            spawn_volume_row(parent, game_assets, settings, "SFX", VolumeChannel::Sfx);
+            spawn_volume_row(parent, game_assets, settings, "Music", VolumeChannel::Music);
+            // Window options
+            spawn_toggle_row(
+                parent,
+                game_assets,
+                settings,
+                "Window",
+                ToggleButton::Fullscreen,
+            );
+            spawn_toggle_row(
+                parent,
+                game_assets,
+                settings,
+                "Resolution",
+                ToggleButton::Resolution,
+            );
+            spawn_toggle_row(parent, game_assets, settings, "VSync", ToggleButton::Vsync);
+            // Accessibility
+            spawn_toggle_row(
+                parent,
+                game_assets,
+                settings,
+                "Screen Effects",
+                ToggleButton::CameraEffects,
+            );
+            // Back
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    BackButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text::from_section("Back", get_button_text_style(game_assets)),
+                        ..default()
+                    });
+                });
+        })
+        .id()
+}
+
+fn spawn_volume_row(
+    parent: &mut ChildBuilder,
+    game_assets: &GameAssets,
+    settings: &Settings,
+    label: &str,
+    channel: VolumeChannel,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: ROW_STYLE,
+            ..default()
+        })
+        .with_children(|parent| {
+            spawn_label(parent, game_assets, label);
+            spawn_volume_button(parent, game_assets, "-", channel, -VOLUME_STEP);
+            parent
+                .spawn(NodeBundle {
+                    style: BAR_STYLE,
+                    background_color: BAR_BACKGROUND_COLOUR.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: get_bar_fill_style(settings.volume(channel)),
+                            background_color: BAR_FILL_COLOUR.into(),
+                            ..default()
+                        },
+                        VolumeBarFill { channel },
+                    ));
+                });
+            spawn_volume_button(parent, game_assets, "+", channel, VOLUME_STEP);
+        });
+}
+
+fn spawn_volume_button(
+    parent: &mut ChildBuilder,
+    game_assets: &GameAssets,
+    label: &str,
+    channel: VolumeChannel,
+    step: f32,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: SMALL_BUTTON_STYLE,
+                background_color: NORMAL_BUTTON_COLOUR.into(),
+                ..default()
+            },
+            VolumeButton { channel, step },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: Text::from_section(label, get_button_text_style(game_assets)),
+                ..default()
+            });
+        });
+}
+
+fn spawn_toggle_row(
+    parent: &mut ChildBuilder,
+    game_assets: &GameAssets,
+    settings: &Settings,
+    label: &str,
+    toggle: ToggleButton,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: ROW_STYLE,
+            ..default()
+        })
+        .with_children(|parent| {
+            spawn_label(parent, game_assets, label);
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: WIDE_BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    toggle,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle {
+                            text: Text::from_section(
+                                get_toggle_label(toggle, settings),
+                                get_button_text_style(game_assets),
+                            ),
+                            ..default()
+                        },
+                        ToggleText { toggle },
+                    ));
+                });
+        });
+}
+
+fn spawn_label(parent: &mut ChildBuilder, game_assets: &GameAssets, label: &str) {
+    parent
+        .spawn(NodeBundle {
+            style: LABEL_STYLE,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: Text::from_section(label, get_button_text_style(game_assets)),
+                ..default()
+            });
+        });
+}
+
+pub fn get_toggle_label(toggle: ToggleButton, settings: &Settings) -> String {
+    match toggle {
+        ToggleButton::Fullscreen if settings.fullscreen => "Fullscreen".to_string(),
+        ToggleButton::Fullscreen => "Windowed".to_string(),
+        ToggleButton::Resolution => {
+            format!("{} x {}", settings.resolution.0, settings.resolution.1)
+        }
+        ToggleButton::Vsync if settings.vsync => "On".to_string(),
+        ToggleButton::Vsync => "Off".to_string(),
+        ToggleButton::CameraEffects if settings.camera_effects => "On".to_string(),
+        ToggleButton::CameraEffects => "Off".to_string(),
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct StatsMenu {}
+#[derive(Component)]
+pub struct BackButton {}
@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::{network::is_authority, SimulationState},
+    AppState,
+};
+
+use self::systems::{
+    interactions::interact_with_back_button,
+    layout::{despawn_stats_menu, spawn_stats_menu},
+    tracking::{
+        record_finished_run, save_lifetime_stats, save_lifetime_stats_on_exit, track_lifetime_stats,
+    },
+};
+use resources::LifetimeStats;
+
+mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LifetimeStats::load())
+            .add_system(
+                track_lifetime_stats
+                    .in_set(OnUpdate(AppState::Game))
+                    .in_set(OnUpdate(SimulationState::Running))
+                    .run_if(is_authority),
+            )
+            .add_system(record_finished_run)
+            .add_system(save_lifetime_stats.in_schedule(OnExit(AppState::Game)))
+            .add_system(save_lifetime_stats_on_exit.in_base_set(CoreSet::Last))
+            .add_system(spawn_stats_menu.in_schedule(OnEnter(AppState::Stats)))
+            .add_system(interact_with_back_button.in_set(OnUpdate(AppState::Stats)))
+            .add_system(despawn_stats_menu.in_schedule(OnExit(AppState::Stats)));
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::persistence;
+
+pub const STATS_FILE: &str = "stats.ron";
+
+// Totals across every session. Only finished runs (ones that ended in a game
+// over) count towards games, scores and survival; stars and play time always count.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub total_games: u32,
+    pub total_stars: u32,
+    pub total_score: u64,
+    pub total_play_time: f32,
+    pub longest_survival: f32,
+    pub deaths_by_enemy: BTreeMap<String, u32>,
+}
+
+impl LifetimeStats {
+    pub fn load() -> LifetimeStats {
+        persistence::load_or_default(STATS_FILE)
+    }
+
+    pub fn save(&self) {
+        persistence::save(STATS_FILE, self);
+    }
+
+    pub fn average_score(&self) -> f32 {
+        if self.total_games == 0 {
+            0.0
+        } else {
+            self.total_score as f32 / self.total_games as f32
+        }
+    }
+}
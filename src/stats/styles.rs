@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::assets::GameAssets;
+
+pub const STAT_LABEL_COLOUR: Color = Color::rgb(0.7, 0.7, 0.7);
+
+pub const STAT_ROW_STYLE: Style = Style {
+    flex_direction: FlexDirection::Row,
+    justify_content: JustifyContent::SpaceBetween,
+    size: Size::new(Val::Px(480.), Val::Px(36.)),
+    ..Style::DEFAULT
+};
+
+pub fn get_stat_text_style(game_assets: &GameAssets, colour: Color) -> TextStyle {
+    TextStyle {
+        font: game_assets.font.clone(),
+        font_size: 28.,
+        color: colour,
+    }
+}
@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+
+use crate::{
+    main_menu::{
+        components::ButtonQuery,
+        styles::{HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOUR, PRESSED_BUTTON_COLOR},
+    },
+    stats::components::BackButton,
+    AppState,
+};
+
+pub fn interact_with_back_button(
+    mut button_query: ButtonQuery<BackButton>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_colour)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Clicked => {
+                *background_colour = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::MainMenu)
+            }
+            Interaction::Hovered => *background_colour = HOVERED_BUTTON_COLOR.into(),
+            Interaction::None => *background_colour = NORMAL_BUTTON_COLOUR.into(),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use crate::{
+    assets::GameAssets,
+    main_menu::styles::{
+        get_button_text_style, get_title_text_style, BUTTON_STYLE, MAIN_MENU_STYLE,
+        NORMAL_BUTTON_COLOUR,
+    },
+    stats::{
+        components::{BackButton, StatsMenu},
+        resources::LifetimeStats,
+        styles::{get_stat_text_style, STAT_LABEL_COLOUR, STAT_ROW_STYLE},
+    },
+};
+
+pub fn spawn_stats_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    lifetime_stats: Res<LifetimeStats>,
+) {
+    build_stats_menu(&mut commands, &game_assets, &lifetime_stats);
+}
+
+pub fn despawn_stats_menu(
+    mut commands: Commands,
+    stats_menu_query: Query<Entity, With<StatsMenu>>,
+) {
+    if let Ok(stats_menu_entity) = stats_menu_query.get_single() {
+        commands.entity(stats_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn build_stats_menu(
+    commands: &mut Commands,
+    game_assets: &GameAssets,
+    lifetime_stats: &LifetimeStats,
+) -> Entity {
+    let mut rows = vec![
+        (
+            "Games played".to_string(),
+            lifetime_stats.total_games.to_string(),
+        ),
+        (
+            "Stars collected".to_string(),
+            lifetime_stats.total_stars.to_string(),
+        ),
+        (
+            "Time played".to_string(),
+            format_duration(lifetime_stats.total_play_time),
+        ),
+        (
+            "Longest survival".to_string(),
+            format_duration(lifetime_stats.longest_survival),
+        ),
+        (
+            "Average score".to_string(),
+            format!("{:.1}", lifetime_stats.average_score()),
+        ),
+    ];
+    for (enemy, deaths) in lifetime_stats.deaths_by_enemy.iter() {
+        rows.push((format!("Deaths to {}", enemy), deaths.to_string()));
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: MAIN_MENU_STYLE,
+                ..default()
+            },
+            StatsMenu {},
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn(TextBundle::from_section(
+                "Stats",
+                get_title_text_style(game_assets),
+            ));
+            // Rows
+            for (label, value) in rows {
+                parent
+                    .spawn(NodeBundle {
+                        style: STAT_ROW_STYLE,
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            label,
+                            get_stat_text_style(game_assets, STAT_LABEL_COLOUR),
+                        ));
+                        parent.spawn(TextBundle::from_section(
+                            value,
+                            get_stat_text_style(game_assets, Color::WHITE),
+                        ));
+                    });
+            }
+            // Back
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BUTTON_STYLE,
+                        background_color: NORMAL_BUTTON_COLOUR.into(),
+                        ..default()
+                    },
+                    BackButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Back",
+                        get_button_text_style(game_assets),
+                    ));
+                });
+        })
+        .id()
+}
+
+// e.g. "1h 02m 05s", "2m 05s".
+fn format_duration(seconds: f32) -> String {
+    let total_seconds = seconds as u32;
+    let (hours, minutes, seconds) = (
+        total_seconds / 3600,
+        (total_seconds / 60) % 60,
+        total_seconds % 60,
+    );
+
+    if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    } else {
+        format!("{}m {:02}s", minutes, seconds)
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+
+use crate::{
+    achievements::resources::RunProgress,
+    events::{GameOver, PlayerHit, StarCollected},
+    game::network::LOCAL_PLAYER_ID,
+    stats::resources::LifetimeStats,
+};
+
+pub fn track_lifetime_stats(
+    mut star_collected_event_reader: EventReader<StarCollected>,
+    mut player_hit_event_reader: EventReader<PlayerHit>,
+    mut lifetime_stats: ResMut<LifetimeStats>,
+    time: Res<Time>,
+) {
+    lifetime_stats.total_play_time += time.delta_seconds();
+    lifetime_stats.total_stars += star_collected_event_reader
+        .iter()
+        .filter(|event| event.player_id == LOCAL_PLAYER_ID)
+        .count() as u32;
+
+    for event in player_hit_event_reader.iter() {
+        if event.player_id == LOCAL_PLAYER_ID {
+            *lifetime_stats
+                .deaths_by_enemy
+                .entry(event.enemy_kind.name().to_string())
+                .or_default() += 1;
+        }
+    }
+}
+
+pub fn record_finished_run(
+    mut game_over_event_reader: EventReader<GameOver>,
+    mut lifetime_stats: ResMut<LifetimeStats>,
+    run_progress: Res<RunProgress>,
+) {
+    for event in game_over_event_reader.iter() {
+        lifetime_stats.total_games += 1;
+        lifetime_stats.total_score += event.score as u64;
+        lifetime_stats.longest_survival =
+            lifetime_stats.longest_survival.max(run_progress.time_alive);
+    }
+}
+
+pub fn save_lifetime_stats(lifetime_stats: Res<LifetimeStats>) {
+    lifetime_stats.save();
+}
+
+// Quitting from the arena skips `OnExit(AppState::Game)`.
+pub fn save_lifetime_stats_on_exit(
+    mut app_exit_event_reader: EventReader<AppExit>,
+    lifetime_stats: Res<LifetimeStats>,
+) {
+    if app_exit_event_reader.iter().last().is_some() {
+        lifetime_stats.save();
+    }
+}
@@ -1,18 +1,39 @@
 use bevy::app::AppExit;
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
+use bevy::window::{PrimaryWindow, WindowResized};
 
-use crate::{events::*, game::SimulationState, AppState};
+use crate::{
+    camera::resources::{CameraEffects, HitStop},
+    events::*,
+    game::{particles::resources::DeathBurst, SimulationState},
+    AppState,
+};
 
-pub fn spawn_camera(mut commands: Commands, window_query: Query<&Window, With<PrimaryWindow>>) {
+pub fn spawn_camera(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut camera_effects: ResMut<CameraEffects>,
+) {
     let window = window_query.get_single().unwrap();
+    camera_effects.centre = Vec2::new(window.width() / 2.0, window.height() / 2.0);
 
     commands.spawn(Camera2dBundle {
-        transform: Transform::from_xyz(window.width() / 2.0, window.height() / 2.0, 0.0),
+        transform: Transform::from_xyz(camera_effects.centre.x, camera_effects.centre.y, 0.0),
         ..default()
     });
 }
 
+// The arena is the window, so keep the camera looking at its centre when the
+// resolution changes. `apply_camera_effects` moves the camera there.
+pub fn recenter_camera(
+    mut window_resized_event_reader: EventReader<WindowResized>,
+    mut camera_effects: ResMut<CameraEffects>,
+) {
+    for event in window_resized_event_reader.iter() {
+        camera_effects.centre = Vec2::new(event.width / 2.0, event.height / 2.0);
+    }
+}
+
 pub fn transition_to_game_state(
     // mut commands: Commands,
     keyboard_input: Res<Input<KeyCode>>,
@@ -57,9 +78,17 @@ pub fn handle_game_over(
     mut game_over_event_reader: EventReader<GameOver>,
     // mut commands: Commands
     mut app_state_next_state: ResMut<NextState<AppState>>,
+    mut game_over_pending: Local<bool>,
+    hit_stop: Res<HitStop>,
+    death_burst: Res<DeathBurst>,
 ) {
     for event in game_over_event_reader.iter() {
         println!("Your final score is: {}", event.score.to_string());
+        *game_over_pending = true;
+    }
+    // Let the hit-stop and the death burst play out on the arena before leaving it.
+    if *game_over_pending && !hit_stop.is_active() && !death_burst.is_playing() {
+        *game_over_pending = false;
         app_state_next_state.set(AppState::GameOver);
         // commands.insert_resource(NextState(Some(AppState::GameOver)));
     }